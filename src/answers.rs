@@ -0,0 +1,120 @@
+use crate::runner::DayId;
+use crate::solution::Part;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+// Answer that is not known yet
+pub const PLACEHOLDER: &str = "?";
+
+// Expected answers of one day, stored as `[<target> <part>]` headers each
+// followed by the answer lines, so that multi-line pictures fit as well
+#[derive(Debug, Default)]
+pub struct Answers {
+    values: BTreeMap<(String, Part), String>,
+}
+
+impl Answers {
+    pub fn path(id: DayId) -> String {
+        format!("{}/answers.txt", id.dir())
+    }
+
+    pub fn load(id: DayId) -> io::Result<Self> {
+        Self::load_from(Self::path(id))
+    }
+
+    // A missing file has no answers yet
+    pub fn load_from(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => content
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, id: DayId) -> io::Result<()> {
+        self.save_to(Self::path(id))
+    }
+
+    pub fn save_to(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, target: &str, part: Part) -> Option<&str> {
+        self.values
+            .get(&(target.to_string(), part))
+            .map(String::as_str)
+            .filter(|&answer| answer != PLACEHOLDER)
+    }
+
+    pub fn set(&mut self, target: &str, part: Part, answer: String) {
+        self.values.insert((target.to_string(), part), answer);
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Self::default();
+        let mut current: Option<((String, Part), Vec<&str>)> = None;
+        for (i, line) in s.lines().enumerate() {
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                if let Some((key, lines)) = current.take() {
+                    answers
+                        .values
+                        .insert(key, lines.join("\n").trim_end().to_string());
+                }
+                let (target, part) = header
+                    .split_once(' ')
+                    .ok_or_else(|| format!("Line {}: expected [<target> <part>]", i + 1))?;
+                let part = part.parse().map_err(|e| format!("Line {}: {e}", i + 1))?;
+                current = Some(((target.to_string(), part), vec![]));
+            } else if let Some((_, lines)) = current.as_mut() {
+                lines.push(line);
+            } else if !line.trim().is_empty() {
+                return Err(format!("Line {}: answer without a header", i + 1));
+            }
+        }
+        if let Some((key, lines)) = current {
+            answers
+                .values
+                .insert(key, lines.join("\n").trim_end().to_string());
+        }
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, ((target, part), answer)) in self.values.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[{target} {part}]\n{answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answers;
+    use crate::solution::Part;
+
+    #[test]
+    fn roundtrip() {
+        let content = "[example 1]\n13140\n\n[example 2]\n##..\n#..#\n\n[main 1]\n14340\n";
+        let answers: Answers = content.parse().unwrap();
+        assert_eq!(answers.get("example", Part::Two), Some("##..\n#..#"));
+        assert_eq!(answers.get("main", Part::Two), None);
+        assert_eq!(answers.to_string(), content);
+
+        let answers: Answers = "[main 1]\n?\n".parse().unwrap();
+        assert_eq!(answers.get("main", Part::One), None);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::error::ParseError;
+use crate::runner::{self, DayId};
+use crate::solution::Part;
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const HISTORY: &str = "bench_history.tsv";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "No samples");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / len as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / len as f64;
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}  stddev {:>10.2?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Bench {
+    pub id: DayId,
+    pub part: Part,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+pub fn bench(
+    id: DayId,
+    part: Part,
+    inp: &str,
+    warmup: usize,
+    reps: usize,
+) -> Result<Bench, ParseError> {
+    for _ in 0..warmup {
+        runner::run(id, part, inp).answer?;
+    }
+    let mut parse = Vec::with_capacity(reps);
+    let mut solve = Vec::with_capacity(reps);
+    for _ in 0..reps {
+        let report = runner::run(id, part, inp);
+        report.answer?;
+        parse.push(report.parse_time);
+        solve.push(report.solve_time);
+    }
+    Ok(Bench {
+        id,
+        part,
+        parse: Stats::from_samples(&parse),
+        solve: Stats::from_samples(&solve),
+    })
+}
+
+fn revision() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+// One tab separated line per measurement: timestamp, revision, day, part,
+// then min/median/mean/stddev of parsing and of solving in nanoseconds, and
+// the year. Lines written before years were added have no year column.
+#[derive(Debug, PartialEq)]
+struct Entry {
+    revision: String,
+    id: DayId,
+    part: Part,
+    parse_median: u128,
+    solve_median: u128,
+}
+
+impl Entry {
+    fn parse(line: &str) -> Option<Self> {
+        let fields: Vec<_> = line.split('\t').collect();
+        let year = match fields.len() {
+            12 => runner::DEFAULT_YEAR,
+            13 => fields[12].parse().ok()?,
+            _ => return None,
+        };
+        Some(Self {
+            revision: fields[1].to_string(),
+            id: DayId::new(year, fields[2].parse().ok()?),
+            part: fields[3].parse().ok()?,
+            parse_median: fields[5].parse().ok()?,
+            solve_median: fields[9].parse().ok()?,
+        })
+    }
+}
+
+fn format_entry(revision: &str, bench: &Bench) -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let mut fields = vec![
+        timestamp.to_string(),
+        revision.to_string(),
+        bench.id.day.to_string(),
+        bench.part.to_string(),
+    ];
+    for stats in [bench.parse, bench.solve] {
+        for d in [stats.min, stats.median, stats.mean, stats.stddev] {
+            fields.push(d.as_nanos().to_string());
+        }
+    }
+    fields.push(bench.id.year.to_string());
+    fields.join("\t")
+}
+
+fn change(old: u128, new: Duration) -> String {
+    if old == 0 {
+        return String::new();
+    }
+    let pct = (new.as_nanos() as f64 / old as f64 - 1.) * 100.;
+    format!("{pct:+.1}%")
+}
+
+// Runs the benchmarks, compares medians with the latest recorded entry of
+// each day and part, and appends the new results to the history file
+pub fn bench_all(jobs: &[(DayId, Part)], target: &str, warmup: usize, reps: usize) {
+    let history = fs::read_to_string(HISTORY).unwrap_or_default();
+    let entries: Vec<_> = history.lines().filter_map(Entry::parse).collect();
+    let revision = revision();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY)
+        .expect("Cannot open history file");
+
+    for &(id, part) in jobs {
+        if let Err(e) = runner::available(id) {
+            println!("{e}");
+            continue;
+        }
+        let inp = runner::read_input(id, target);
+        println!("{id} part {part} ({reps} runs, {warmup} warmup)");
+        let result = match bench(id, part, &inp, warmup, reps) {
+            Ok(result) => result,
+            Err(e) => {
+                println!("{e}");
+                continue;
+            }
+        };
+        let previous = entries.iter().rev().find(|e| e.id == id && e.part == part);
+        let (parse_change, solve_change) = match previous {
+            Some(prev) => (
+                format!(
+                    "  {} vs {}",
+                    change(prev.parse_median, result.parse.median),
+                    prev.revision
+                ),
+                format!(
+                    "  {} vs {}",
+                    change(prev.solve_median, result.solve.median),
+                    prev.revision
+                ),
+            ),
+            None => (String::new(), String::new()),
+        };
+        println!("  parse: {}{parse_change}", result.parse);
+        println!("  solve: {}{solve_change}", result.solve);
+        writeln!(file, "{}", format_entry(&revision, &result)).expect("Cannot write history");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_entry, Bench, Entry, Stats};
+    use crate::runner::DayId;
+    use crate::solution::Part;
+    use std::time::Duration;
+
+    #[test]
+    fn stats() {
+        let samples: Vec<_> = [4, 1, 3, 2]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn history_roundtrip() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(10), Duration::from_nanos(30)]);
+        let bench = Bench {
+            id: DayId::new(2023, 7),
+            part: Part::Two,
+            parse: stats,
+            solve: stats,
+        };
+        assert_eq!(
+            Entry::parse(&format_entry("abc1234", &bench)),
+            Some(Entry {
+                revision: "abc1234".to_string(),
+                id: DayId::new(2023, 7),
+                part: Part::Two,
+                parse_median: 20,
+                solve_median: 20,
+            })
+        );
+        // Lines from before the year column
+        let old = "1700000000\tabc1234\t7\t2\t1\t2\t3\t4\t5\t6\t7\t8";
+        assert_eq!(Entry::parse(old).unwrap().id, DayId::new(2022, 7));
+    }
+}
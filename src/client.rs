@@ -0,0 +1,321 @@
+use crate::answers::Answers;
+use crate::runner::DayId;
+use crate::solution::Part;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+pub const DEFAULT_BASE: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "aoc22 input client";
+
+// Where the puzzles come from and where they go on disk
+#[derive(Clone, Debug)]
+pub struct Config {
+    // The site, or a mock of it, without a trailing slash
+    pub base: String,
+    pub session: String,
+    // Holds the `inputs` tree, and the time of the last request
+    pub root: PathBuf,
+    // Least time between two requests, also across runs
+    pub interval: Duration,
+}
+
+impl Config {
+    pub fn new(base: &str, session: &str) -> Self {
+        Self {
+            base: base.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            root: PathBuf::from("."),
+            interval: Duration::from_secs(5),
+        }
+    }
+}
+
+// What the site made of a submitted answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    // With the hint of the site, like "your answer is too high"
+    Wrong(String),
+    // Submitted too soon after the previous one, with the time left to wait
+    TooSoon(String),
+    // The part is solved already, but the answer is not in the answers file
+    Solved,
+    // The answer in the answers file, nothing was submitted
+    Known(String),
+    Unrecognised(String),
+}
+
+// The message of the page, without its markup
+fn article(html: &str) -> String {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |end| start + end);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl Verdict {
+    fn parse(html: &str) -> Self {
+        let text = article(html);
+        let sentence = |from: usize| {
+            text[from..]
+                .split_once('.')
+                .map_or(&text[from..], |(sentence, _)| sentence)
+                .trim()
+                .to_string()
+        };
+        if text.contains("That's the right answer") {
+            Self::Right
+        } else if text.contains("That's not the right answer") {
+            Self::Wrong(text.find("your answer is").map_or(String::new(), sentence))
+        } else if text.contains("You gave an answer too recently") {
+            Self::TooSoon(text.find("You have").map_or(String::new(), sentence))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Self::Solved
+        } else {
+            Self::Unrecognised(text)
+        }
+    }
+}
+
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Self { config, agent }
+    }
+
+    pub fn input_path(&self, id: DayId) -> PathBuf {
+        self.config.root.join(id.dir()).join("main.txt")
+    }
+
+    pub fn answers_path(&self, id: DayId) -> PathBuf {
+        self.config.root.join(id.dir()).join("answers.txt")
+    }
+
+    // Sleeps until `interval` has passed since the last request of any run
+    fn wait_turn(&self) -> Result<(), String> {
+        let stamp = self.config.root.join(".aoc-client");
+        let last = fs::metadata(&stamp).and_then(|meta| meta.modified());
+        if let Ok(last) = last {
+            let since = SystemTime::now().duration_since(last).unwrap_or_default();
+            thread::sleep(self.config.interval.saturating_sub(since));
+        }
+        fs::write(&stamp, "").map_err(|e| format!("Cannot write {}: {e}", stamp.display()))
+    }
+
+    fn send(
+        &self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, String> {
+        self.wait_turn()?;
+        let url = request.url().to_string();
+        let request = request.set("Cookie", &format!("session={}", self.config.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => response.into_string().map_err(|e| format!("{url}: {e}")),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(format!("{url}: HTTP {code} {}", body.trim()))
+            }
+            Err(e) => Err(format!("{url}: {e}")),
+        }
+    }
+
+    fn url(&self, id: DayId, page: &str) -> String {
+        format!("{}/{}/day/{}/{page}", self.config.base, id.year, id.day)
+    }
+
+    // Downloads the main input of the day, unless it is there already. Returns
+    // where it is and whether it was downloaded.
+    pub fn fetch(&self, id: DayId, force: bool) -> Result<(PathBuf, bool), String> {
+        let path = self.input_path(id);
+        if path.exists() && !force {
+            return Ok((path, false));
+        }
+        let inp = self.send(self.agent.get(&self.url(id, "input")), None)?;
+        let write = || {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, inp)
+        };
+        write().map_err(|e| format!("Cannot write {}: {e}", path.display()))?;
+        Ok((path, true))
+    }
+
+    // Submits the answer of the main input, unless the answers file has it.
+    // Right answers go into the answers file.
+    pub fn submit(&self, id: DayId, part: Part, answer: &str) -> Result<Verdict, String> {
+        let path = self.answers_path(id);
+        let broken = |e| format!("Broken answers file {}: {e}", path.display());
+        let mut answers = Answers::load_from(&path).map_err(broken)?;
+        if let Some(known) = answers.get("main", part) {
+            return Ok(Verdict::Known(known.to_string()));
+        }
+        let level = u8::from(part).to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let html = self.send(self.agent.post(&self.url(id, "answer")), Some(&form))?;
+        let verdict = Verdict::parse(&html);
+        if verdict == Verdict::Right {
+            answers.set("main", part, answer.to_string());
+            fs::create_dir_all(path.parent().unwrap()).map_err(broken)?;
+            answers.save_to(&path).map_err(broken)?;
+        }
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Client, Config, Verdict};
+    use crate::answers::Answers;
+    use crate::runner::DayId;
+    use crate::serve::read_request;
+    use crate::solution::Part;
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use std::{env, fs, thread};
+
+    const RIGHT: &str = "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>";
+    const WRONG: &str = "<main><article><p>That's not the right answer; your answer is too low. If you're stuck, <a href=\"/2022/about\">ask</a>.</p></article></main>";
+
+    // The puzzle site for day 1: the input is `1\n2\n` and the answer of part 1
+    // is 3. Counts the requests it gets.
+    fn mock() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request = read_request(&stream).unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                let body = String::from_utf8(request.body.clone()).unwrap();
+                let (status, page) = match (request.method.as_str(), request.path.as_str()) {
+                    _ if request.header("cookie") != Some("session=secret") => (
+                        400,
+                        "Puzzle inputs differ by user. Please log in to get your puzzle input.",
+                    ),
+                    ("GET", "/2022/day/1/input") => (200, "1\n2\n"),
+                    ("POST", "/2022/day/1/answer") if body == "level=1&answer=3" => (200, RIGHT),
+                    ("POST", "/2022/day/1/answer") => (200, WRONG),
+                    _ => (404, "404 Not Found"),
+                };
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                    page.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (base, requests)
+    }
+
+    fn root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc22-client-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    fn connect(root: &Path, base: &str, session: &str) -> Client {
+        let mut config = Config::new(base, session);
+        config.root = root.to_path_buf();
+        config.interval = Duration::from_millis(100);
+        Client::new(config)
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(Verdict::parse(RIGHT), Verdict::Right);
+        assert_eq!(
+            Verdict::parse(WRONG),
+            Verdict::Wrong("your answer is too low".to_string())
+        );
+        let soon = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+        assert_eq!(
+            Verdict::parse(soon),
+            Verdict::TooSoon("You have 34s left to wait".to_string())
+        );
+        let solved = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+        assert_eq!(Verdict::parse(solved), Verdict::Solved);
+    }
+
+    #[test]
+    fn fetches_and_caches_inputs() {
+        let (base, requests) = mock();
+        let root = root("fetch");
+        let client = connect(&root, &base, "secret");
+        let id = DayId::new(2022, 1);
+        let (path, downloaded) = client.fetch(id, false).unwrap();
+        assert!(downloaded);
+        assert_eq!(path, root.join("inputs/2022/task1/main.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n");
+        assert_eq!(client.fetch(id, false).unwrap(), (path, false));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // Waits for its turn
+        let start = Instant::now();
+        assert!(client.fetch(id, true).unwrap().1);
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        let error = client.fetch(DayId::new(2022, 2), false).unwrap_err();
+        assert!(error.contains("404"), "{error}");
+        let stranger = connect(&root, &base, "stolen");
+        let error = stranger.fetch(id, true).unwrap_err();
+        assert!(error.contains("HTTP 400 Puzzle inputs differ"), "{error}");
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn records_right_answers() {
+        let (base, requests) = mock();
+        let root = root("submit");
+        let client = connect(&root, &base, "secret");
+        let id = DayId::new(2022, 1);
+        assert_eq!(
+            client.submit(id, Part::One, "4").unwrap(),
+            Verdict::Wrong("your answer is too low".to_string())
+        );
+        assert!(!client.answers_path(id).exists());
+        assert_eq!(client.submit(id, Part::One, "3").unwrap(), Verdict::Right);
+        let answers = Answers::load_from(client.answers_path(id)).unwrap();
+        assert_eq!(answers.get("main", Part::One), Some("3"));
+        // Known answers are not submitted again
+        assert_eq!(
+            client.submit(id, Part::One, "5").unwrap(),
+            Verdict::Known("3".to_string())
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
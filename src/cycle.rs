@@ -0,0 +1,114 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    // First step of the repeating part
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // Recorded step equivalent to `step`, and how many whole periods lie between them
+    pub fn reduce(&self, step: usize) -> (usize, usize) {
+        if step < self.start {
+            return (step, 0);
+        }
+        let offset = step - self.start;
+        (self.start + offset % self.period, offset / self.period)
+    }
+}
+
+// Records a state key and a measurement after every step of a simulation,
+// until some state comes back
+#[derive(Debug)]
+pub struct CycleFinder<K, M> {
+    seen: HashMap<K, usize>,
+    history: Vec<M>,
+    cycle: Option<Cycle>,
+}
+
+impl<K: Eq + Hash, M> Default for CycleFinder<K, M> {
+    fn default() -> Self {
+        Self {
+            seen: HashMap::new(),
+            history: vec![],
+            cycle: None,
+        }
+    }
+}
+
+impl<K: Eq + Hash, M> CycleFinder<K, M> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The first push is step 0. Returns the cycle once the key has been seen
+    // before, later pushes are ignored
+    pub fn push(&mut self, key: K, value: M) -> Option<Cycle> {
+        if self.cycle.is_some() {
+            return self.cycle;
+        }
+        let step = self.history.len();
+        self.history.push(value);
+        match self.seen.entry(key) {
+            Entry::Occupied(e) => {
+                self.cycle = Some(Cycle {
+                    start: *e.get(),
+                    period: step - e.get(),
+                });
+            }
+            Entry::Vacant(e) => {
+                e.insert(step);
+            }
+        }
+        self.cycle
+    }
+
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    pub fn history(&self) -> &[M] {
+        &self.history
+    }
+}
+
+impl<K: Eq + Hash> CycleFinder<K, i64> {
+    // Measurement at any step, assuming it changes by the same amount every period
+    pub fn extrapolate(&self, step: usize) -> Option<i64> {
+        if step < self.history.len() {
+            return Some(self.history[step]);
+        }
+        let cycle = self.cycle?;
+        let (recorded, periods) = cycle.reduce(step);
+        let gain = self.history[cycle.start + cycle.period] - self.history[cycle.start];
+        Some(self.history[recorded] + gain * periods as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cycle, CycleFinder};
+
+    #[test]
+    fn extrapolates() {
+        // State goes 0, 1, 2, 3, 4, 2, 3, 4, ... and the value sums the states
+        let state = |step: usize| if step < 2 { step } else { 2 + (step - 2) % 3 };
+        let value = |step: usize| (0..=step).map(state).sum::<usize>() as i64;
+        let mut finder = CycleFinder::new();
+        let cycle = (0..).find_map(|step| finder.push(state(step), value(step)));
+        assert_eq!(
+            cycle,
+            Some(Cycle {
+                start: 2,
+                period: 3
+            })
+        );
+        assert_eq!(finder.history(), [0, 1, 3, 6, 10, 12]);
+        for step in [4, 8, 1000, 12345] {
+            assert_eq!(finder.extrapolate(step), Some(value(step)));
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    // 1-based, zero when the offending text is not a part of the input
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+    line_text: String,
+    addr: usize,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    // `text` should be borrowed from the parsed input, so that `locate` can
+    // find where it came from
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        Self {
+            day: 0,
+            line: 0,
+            column: 0,
+            text: text.to_string(),
+            message: message.into(),
+            line_text: String::new(),
+            addr: text.as_ptr() as usize,
+        }
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = day;
+        self
+    }
+
+    // Resolves line and column of the offending text within the whole input
+    pub fn locate(mut self, inp: &str) -> Self {
+        let start = inp.as_ptr() as usize;
+        if self.addr < start || self.addr > start + inp.len() {
+            return self;
+        }
+        let offset = self.addr - start;
+        let line_start = inp[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = inp[offset..].find('\n').map_or(inp.len(), |i| offset + i);
+        self.line = inp[..offset].matches('\n').count() + 1;
+        self.column = inp[line_start..offset].chars().count() + 1;
+        self.line_text = inp[line_start..line_end].to_string();
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.day > 0 {
+            write!(f, "day {}: ", self.day)?;
+        }
+        if self.line == 0 {
+            return write!(f, "{} in {:?}", self.message, self.text);
+        }
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        let width = self
+            .text
+            .lines()
+            .next()
+            .map_or(1, |l| l.chars().count().max(1));
+        writeln!(f, "    {}", self.line_text)?;
+        write!(
+            f,
+            "    {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// Parses a single field, reporting the field itself on failure
+pub fn field<T: FromStr>(text: &str, what: &str) -> ParseResult<T> {
+    text.parse()
+        .map_err(|_| ParseError::new(text, format!("Invalid {what}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{field, ParseError};
+
+    #[test]
+    fn locates_borrowed_text() {
+        let inp = "1,2,3\n4,x,6\n";
+        let token = inp.lines().nth(1).unwrap().split(',').nth(1).unwrap();
+        let err = field::<i32>(token, "coordinate")
+            .unwrap_err()
+            .locate(inp)
+            .in_day(18);
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x"));
+        assert_eq!(
+            err.to_string(),
+            "day 18: line 2, column 3: Invalid coordinate\n    4,x,6\n      ^"
+        );
+    }
+
+    #[test]
+    fn owned_text_has_no_location() {
+        let err = ParseError::new("abc", "Unknown").locate("xyz");
+        assert_eq!(err.line, 0);
+        assert_eq!(err.to_string(), "Unknown in \"abc\"");
+    }
+}
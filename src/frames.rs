@@ -0,0 +1,335 @@
+use crate::grid::Grid;
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+pub type Colour = [u8; 3];
+
+// Cells that are not in the palette of a frame
+const DEFAULT_COLOUR: Colour = [160, 160, 160];
+const BACKGROUND: Colour = [0, 0, 0];
+
+// State of a simulation after one of its steps, one character per cell
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub step: usize,
+    pub cells: Grid<char>,
+    pub palette: &'static [(char, Colour)],
+}
+
+impl Frame {
+    pub fn new(step: usize, cells: Grid<char>, palette: &'static [(char, Colour)]) -> Self {
+        Self {
+            step,
+            cells,
+            palette,
+        }
+    }
+
+    // Cells shown by the first character they display as
+    pub fn from_display<T: Display>(
+        step: usize,
+        grid: &Grid<T>,
+        palette: &'static [(char, Colour)],
+    ) -> Self {
+        let cells = grid.map(|cell| cell.to_string().chars().next().unwrap_or(' '));
+        Self::new(step, cells, palette)
+    }
+
+    // Spaces and dots are background unless the palette says otherwise
+    pub fn colour(&self, c: char) -> Colour {
+        match self.palette.iter().find(|&&(p, _)| p == c) {
+            Some(&(_, colour)) => colour,
+            None if c == ' ' || c == '.' => BACKGROUND,
+            None => DEFAULT_COLOUR,
+        }
+    }
+}
+
+pub trait Sink {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+// Every frame as text, one after the other
+pub struct Ascii<W: Write> {
+    out: W,
+}
+
+impl<W: Write> Ascii<W> {
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+}
+
+impl<W: Write> Sink for Ascii<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        writeln!(self.out, "Step {}\n{}\n", frame.step, frame.cells)?;
+        self.out.flush()
+    }
+}
+
+// Redraws the terminal in colour for every frame, then waits for `delay`
+pub struct Ansi<W: Write> {
+    out: W,
+    delay: Duration,
+}
+
+impl<W: Write> Ansi<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        Self { out, delay }
+    }
+}
+
+impl<W: Write> Sink for Ansi<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut text = format!("\x1b[H\x1b[2JStep {}\n", frame.step);
+        for r in 0..frame.cells.rows() {
+            for &c in frame.cells.row(r) {
+                let [red, green, blue] = frame.colour(c);
+                text += &format!("\x1b[38;2;{red};{green};{blue}m{c}");
+            }
+            text += "\x1b[0m\n";
+        }
+        self.out.write_all(text.as_bytes())?;
+        self.out.flush()?;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+// Numbered binary PPM images in a directory, `scale` pixels to a cell side
+pub struct Ppm {
+    dir: PathBuf,
+    scale: usize,
+    written: usize,
+}
+
+impl Ppm {
+    pub fn new(dir: impl Into<PathBuf>, scale: usize) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            scale: scale.max(1),
+            written: 0,
+        })
+    }
+}
+
+impl Sink for Ppm {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let (rows, cols) = frame.cells.size();
+        let path = self.dir.join(format!("frame_{:05}.ppm", self.written));
+        let mut out = BufWriter::new(File::create(path)?);
+        write!(
+            out,
+            "P6\n{} {}\n255\n",
+            cols * self.scale,
+            rows * self.scale
+        )?;
+        for r in 0..rows {
+            let line: Vec<u8> = frame
+                .cells
+                .row(r)
+                .iter()
+                .flat_map(|&c| frame.colour(c).repeat(self.scale))
+                .collect();
+            for _ in 0..self.scale {
+                out.write_all(&line)?;
+            }
+        }
+        out.flush()?;
+        self.written += 1;
+        Ok(())
+    }
+}
+
+// Where frames go, from `ascii:<file>`, `ansi[:<delay in ms>]` or `ppm:<directory>[:<scale>]`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Target {
+    Ascii(PathBuf),
+    Ansi(Duration),
+    Ppm(PathBuf, usize),
+}
+
+impl Target {
+    pub fn open(&self) -> io::Result<Box<dyn Sink>> {
+        Ok(match self {
+            Self::Ascii(path) => Box::new(Ascii::new(BufWriter::new(File::create(path)?))),
+            Self::Ansi(delay) => Box::new(Ansi::new(io::stderr(), *delay)),
+            Self::Ppm(dir, scale) => Box::new(Ppm::new(dir, *scale)?),
+        })
+    }
+}
+
+impl FromStr for Target {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn number<T: FromStr>(n: &str) -> Result<T, String> {
+            n.parse().map_err(|_| format!("Expected a number, not {n}"))
+        }
+        match s.split_once(':').unwrap_or((s, "")) {
+            ("ascii", path) if !path.is_empty() => Ok(Self::Ascii(path.into())),
+            ("ansi", "") => Ok(Self::Ansi(Duration::from_millis(50))),
+            ("ansi", delay) => Ok(Self::Ansi(Duration::from_millis(number(delay)?))),
+            ("ppm", dir) if !dir.is_empty() => match dir.rsplit_once(':') {
+                Some((dir, scale)) => Ok(Self::Ppm(dir.into(), number(scale)?)),
+                None => Ok(Self::Ppm(dir.into(), 4)),
+            },
+            _ => Err(format!("Unknown frame target {s}")),
+        }
+    }
+}
+
+struct Capture {
+    sink: Box<dyn Sink>,
+    frames: usize,
+    error: Option<io::Error>,
+}
+
+thread_local! {
+    static CAPTURE: RefCell<Option<Capture>> = const { RefCell::new(None) };
+}
+
+// Puts back the previous capture, even if the simulation panics
+struct Restore(Option<Capture>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CAPTURE.with(|capture| *capture.borrow_mut() = self.0.take());
+    }
+}
+
+// Runs `f` with its frames going to `sink`. Also gives the number of frames
+// written, or the error that stopped the writing.
+pub fn capture<T>(sink: Box<dyn Sink>, f: impl FnOnce() -> T) -> (T, io::Result<usize>) {
+    let capture = Capture {
+        sink,
+        frames: 0,
+        error: None,
+    };
+    let previous = CAPTURE.with(|current| current.borrow_mut().replace(capture));
+    let restore = Restore(previous);
+    let value = f();
+    let capture = CAPTURE.with(|current| current.borrow_mut().take());
+    drop(restore);
+    let written = match capture {
+        Some(Capture { error: Some(e), .. }) => Err(e),
+        Some(Capture { frames, .. }) => Ok(frames),
+        None => Ok(0),
+    };
+    (value, written)
+}
+
+// Simulations only build their frames when someone is watching
+pub fn enabled() -> bool {
+    CAPTURE.with(|capture| capture.borrow().as_ref().is_some_and(|c| c.error.is_none()))
+}
+
+pub fn emit(frame: Frame) {
+    CAPTURE.with(|capture| {
+        if let Some(capture) = capture.borrow_mut().as_mut() {
+            if capture.error.is_some() {
+                return;
+            }
+            match capture.sink.frame(&frame) {
+                Ok(()) => capture.frames += 1,
+                Err(e) => capture.error = Some(e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{capture, emit, enabled, Ascii, Frame, Ppm, Sink, Target};
+    use crate::grid::Grid;
+    use crate::runner::{self, DayId, DEFAULT_YEAR};
+    use crate::solution::Part;
+    use std::cell::RefCell;
+    use std::fs;
+    use std::io;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    #[derive(Clone, Default)]
+    struct Shared(Rc<RefCell<Vec<u8>>>);
+
+    impl io::Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn frame(step: usize) -> Frame {
+        let cells = Grid::parse("#.\n.o", Some).unwrap();
+        Frame::new(step, cells, &[('o', [255, 200, 0])])
+    }
+
+    #[test]
+    fn parses_targets() {
+        assert_eq!("ascii:out.txt".parse(), Ok(Target::Ascii("out.txt".into())));
+        assert_eq!("ansi".parse(), Ok(Target::Ansi(Duration::from_millis(50))));
+        assert_eq!("ansi:5".parse(), Ok(Target::Ansi(Duration::from_millis(5))));
+        assert_eq!("ppm:out".parse(), Ok(Target::Ppm("out".into(), 4)));
+        assert_eq!("ppm:out:1".parse(), Ok(Target::Ppm("out".into(), 1)));
+        assert!("ascii".parse::<Target>().is_err());
+        assert!("gif:out".parse::<Target>().is_err());
+    }
+
+    #[test]
+    fn captures_frames() {
+        let out = Shared::default();
+        assert!(!enabled());
+        let ((), written) = capture(Box::new(Ascii::new(out.clone())), || {
+            assert!(enabled());
+            emit(frame(1));
+            emit(frame(2));
+        });
+        assert_eq!(written.unwrap(), 2);
+        assert!(!enabled());
+        let text = String::from_utf8(out.0.borrow().clone()).unwrap();
+        assert_eq!(text, "Step 1\n#.\n.o\n\nStep 2\n#.\n.o\n\n");
+    }
+
+    #[test]
+    fn writes_ppm_images() {
+        let dir = std::env::temp_dir().join(format!("aoc22-frames-{}", std::process::id()));
+        let mut ppm = Ppm::new(&dir, 2).unwrap();
+        ppm.frame(&frame(0)).unwrap();
+        let image = fs::read(dir.join("frame_00000.ppm")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        let pixels = &image[header.len()..];
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        // Bottom right cell
+        assert_eq!(pixels[pixels.len() - 3..], [255, 200, 0]);
+        assert_eq!(pixels[..3], [160, 160, 160]);
+    }
+
+    #[test]
+    fn simulations_emit_frames() {
+        for (day, frames) in [(14, 24), (17, 63), (22, 13), (23, 10), (24, 19)] {
+            let id = DayId::new(DEFAULT_YEAR, day);
+            if runner::available(id).is_err() {
+                continue;
+            }
+            let inp = runner::read_input(id, "example");
+            let out = Shared::default();
+            let (report, written) = capture(Box::new(Ascii::new(out.clone())), || {
+                runner::run(id, Part::One, &inp)
+            });
+            assert!(report.answer.is_ok(), "{id}");
+            assert_eq!(written.unwrap(), frames, "{id}");
+        }
+    }
+}
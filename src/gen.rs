@@ -0,0 +1,143 @@
+use crate::runner::DayId;
+use crate::solution;
+use std::ops::RangeInclusive;
+
+// Random puzzle input of roughly `size` lines or cells, in the format of one day
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+// Day N of a year is at index N - 1, `None` if its feature is off
+const Y2022: [Option<Generator>; 25] = [
+    solution::enabled!("y2022-day1", crate::y2022::day1::generate),
+    solution::enabled!("y2022-day2", crate::y2022::day2::generate),
+    solution::enabled!("y2022-day3", crate::y2022::day3::generate),
+    solution::enabled!("y2022-day4", crate::y2022::day4::generate),
+    solution::enabled!("y2022-day5", crate::y2022::day5::generate),
+    solution::enabled!("y2022-day6", crate::y2022::day6::generate),
+    solution::enabled!("y2022-day7", crate::y2022::day7::generate),
+    solution::enabled!("y2022-day8", crate::y2022::day8::generate),
+    solution::enabled!("y2022-day9", crate::y2022::day9::generate),
+    solution::enabled!("y2022-day10", crate::y2022::day10::generate),
+    solution::enabled!("y2022-day11", crate::y2022::day11::generate),
+    solution::enabled!("y2022-day12", crate::y2022::day12::generate),
+    solution::enabled!("y2022-day13", crate::y2022::day13::generate),
+    solution::enabled!("y2022-day14", crate::y2022::day14::generate),
+    solution::enabled!("y2022-day15", crate::y2022::day15::generate),
+    solution::enabled!("y2022-day16", crate::y2022::day16::generate),
+    solution::enabled!("y2022-day17", crate::y2022::day17::generate),
+    solution::enabled!("y2022-day18", crate::y2022::day18::generate),
+    solution::enabled!("y2022-day19", crate::y2022::day19::generate),
+    solution::enabled!("y2022-day20", crate::y2022::day20::generate),
+    solution::enabled!("y2022-day21", crate::y2022::day21::generate),
+    solution::enabled!("y2022-day22", crate::y2022::day22::generate),
+    solution::enabled!("y2022-day23", crate::y2022::day23::generate),
+    solution::enabled!("y2022-day24", crate::y2022::day24::generate),
+    solution::enabled!("y2022-day25", crate::y2022::day25::generate),
+];
+
+const GENERATORS: [(u16, &[Option<Generator>]); 1] = [(2022, &Y2022)];
+
+// SplitMix64, small and good enough for test inputs. The same seed always
+// gives the same input.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in `0..n`
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    // Uniform in the range, both ends included
+    pub fn range<T>(&mut self, range: RangeInclusive<T>) -> T
+    where
+        T: Copy + TryInto<i128> + TryFrom<i128>,
+    {
+        let wide = |x: T| x.try_into().ok().expect("Range must fit into i128");
+        let (lo, hi) = (wide(*range.start()), wide(*range.end()));
+        assert!(lo <= hi, "Empty range");
+        let offset = (self.next_u64() as u128 % (hi - lo + 1) as u128) as i128;
+        T::try_from(lo + offset).ok().expect("In range")
+    }
+
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+pub fn generator(id: DayId) -> Option<Generator> {
+    let (_, days) = GENERATORS.iter().find(|&&(year, _)| year == id.year)?;
+    *days.get((id.day as usize).checked_sub(1)?)?
+}
+
+pub fn generate(id: DayId, size: usize, seed: u64) -> Option<String> {
+    generator(id).map(|generate| generate(&mut Rng::new(seed), size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, generator, Rng};
+    use crate::runner::{self, DayId, DEFAULT_YEAR};
+    use crate::solution::Part;
+
+    #[test]
+    fn rng_ranges() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3i64..=3)));
+            assert!(rng.below(5) < 5);
+        }
+        assert_eq!(rng.range(4usize..=4), 4);
+        let mut items: Vec<_> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert_eq!(Rng::new(1).next_u64(), Rng::new(1).next_u64());
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for id in DayId::all(DEFAULT_YEAR) {
+            // Days fresh from `new-day` have no generator yet
+            if generator(id).is_none() {
+                continue;
+            }
+            // Every blueprint takes about a second without optimizations
+            let size = if id.day == 19 { 1 } else { 20 };
+            for seed in 0..3 {
+                let inp = generate(id, size, seed).unwrap();
+                assert_eq!(inp, generate(id, size, seed).unwrap());
+                let report = runner::run(id, Part::One, &inp);
+                assert!(
+                    report.answer.is_ok(),
+                    "{id}, seed {seed}: {:?}",
+                    report.answer
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use crate::grid::Pos;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// x grows to the right and y grows downwards, as rows do in a grid
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+// Componentwise arithmetic shared by both point types
+macro_rules! point {
+    ($point:ident, $($c:ident),+) => {
+        impl $point {
+            pub const ZERO: Self = Self { $($c: 0),+ };
+            pub const ONES: Self = Self { $($c: 1),+ };
+
+            pub const fn new($($c: isize),+) -> Self {
+                Self { $($c),+ }
+            }
+            pub fn manhattan(self, other: Self) -> usize {
+                0 $(+ self.$c.abs_diff(other.$c))+
+            }
+            pub fn signum(self) -> Self {
+                Self { $($c: self.$c.signum()),+ }
+            }
+            pub fn min(self, other: Self) -> Self {
+                Self { $($c: self.$c.min(other.$c)),+ }
+            }
+            pub fn max(self, other: Self) -> Self {
+                Self { $($c: self.$c.max(other.$c)),+ }
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                Self { $($c: self.$c + other.$c),+ }
+            }
+        }
+        impl Sub for $point {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self {
+                Self { $($c: self.$c - other.$c),+ }
+            }
+        }
+        impl Mul<isize> for $point {
+            type Output = Self;
+            fn mul(self, k: isize) -> Self {
+                Self { $($c: self.$c * k),+ }
+            }
+        }
+        impl Neg for $point {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl Coords for $point {
+            const ONES: Self = Self::ONES;
+            fn min(self, other: Self) -> Self {
+                self.min(other)
+            }
+            fn max(self, other: Self) -> Self {
+                self.max(other)
+            }
+            fn all_le(self, other: Self) -> bool {
+                $(self.$c <= other.$c)&&+
+            }
+        }
+    };
+}
+
+point!(Point, x, y);
+point!(Point3, x, y, z);
+
+impl Point {
+    pub const NEIGHBOURS8: [Point; 8] = [
+        Point::new(-1, -1),
+        Point::new(0, -1),
+        Point::new(1, -1),
+        Point::new(1, 0),
+        Point::new(1, 1),
+        Point::new(0, 1),
+        Point::new(-1, 1),
+        Point::new(-1, 0),
+    ];
+
+    // Quarter turns around the origin, as seen on the screen
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    // Row and column in a grid, if both are non-negative
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+}
+
+impl From<Pos> for Point {
+    fn from((r, c): Pos) -> Self {
+        Self::new(c as isize, r as isize)
+    }
+}
+
+impl Point3 {
+    pub const NEIGHBOURS6: [Point3; 6] = [
+        Point3::new(1, 0, 0),
+        Point3::new(-1, 0, 0),
+        Point3::new(0, 1, 0),
+        Point3::new(0, -1, 0),
+        Point3::new(0, 0, 1),
+        Point3::new(0, 0, -1),
+    ];
+
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        Self::NEIGHBOURS6.into_iter().map(move |d| self + d)
+    }
+}
+
+pub trait Coords:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<isize, Output = Self>
+{
+    const ONES: Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    // Every coordinate is at most the one of `other`
+    fn all_le(self, other: Self) -> bool;
+}
+
+// Both corners are included
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds<P> {
+    pub min: P,
+    pub max: P,
+}
+
+impl<P: Coords> Bounds<P> {
+    // Smallest box holding all the points, `None` if there are none
+    pub fn from_points(points: impl IntoIterator<Item = P>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            Self {
+                min: first,
+                max: first,
+            },
+            |b, p| Self {
+                min: b.min.min(p),
+                max: b.max.max(p),
+            },
+        ))
+    }
+    pub fn contains(&self, p: P) -> bool {
+        self.min.all_le(p) && p.all_le(self.max)
+    }
+    // Number of points along each axis
+    pub fn size(&self) -> P {
+        self.max - self.min + P::ONES
+    }
+    pub fn grow(&self, by: isize) -> Self {
+        Self {
+            min: self.min - P::ONES * by,
+            max: self.max + P::ONES * by,
+        }
+    }
+}
+
+// In clockwise order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Dir::Up => Point::new(0, -1),
+            Dir::Right => Point::new(1, 0),
+            Dir::Down => Point::new(0, 1),
+            Dir::Left => Point::new(-1, 0),
+        }
+    }
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir::Up | Dir::Down)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bounds, Dir, Point, Point3};
+
+    #[test]
+    fn turns_and_rotations() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_right().offset(), dir.offset().rotate_right());
+            assert_eq!(dir.turn_left().offset(), dir.offset().rotate_left());
+            assert_eq!(dir.reverse().offset(), -dir.offset());
+            assert_eq!(dir.turn_right().turn_left(), dir);
+        }
+        assert_eq!(Dir::Up.turn_right(), Dir::Right);
+        assert_eq!(Dir::Left.turn_right(), Dir::Up);
+        assert_eq!(Point::from((2, 5)), Point::new(5, 2));
+        assert_eq!(Point::new(5, 2).to_pos(), Some((2, 5)));
+        assert_eq!(Point::new(-1, 2).to_pos(), None);
+    }
+
+    #[test]
+    fn distances_and_bounds() {
+        assert_eq!(Point::new(2, 18).manhattan(Point::new(-2, 15)), 7);
+        assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::ZERO), 6);
+        assert_eq!(Point::new(3, -4).signum() * 2, Point::new(2, -2));
+
+        let bounds = Bounds::from_points([Point3::new(1, 5, 2), Point3::new(3, 2, 2)]).unwrap();
+        assert_eq!(bounds.min, Point3::new(1, 2, 2));
+        assert_eq!(bounds.size(), Point3::new(3, 4, 1));
+        assert!(bounds.contains(Point3::new(2, 3, 2)));
+        assert!(!bounds.contains(Point3::new(2, 3, 3)));
+        assert!(bounds.grow(1).contains(Point3::new(2, 3, 3)));
+        assert_eq!(Bounds::<Point>::from_points([]), None);
+    }
+}
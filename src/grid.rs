@@ -0,0 +1,262 @@
+use crate::error::{ParseError, ParseResult};
+use crate::geometry::{Dir, Point};
+use std::fmt::{self, Display};
+use std::iter;
+use std::ops::{Index, IndexMut, Range};
+
+// Row and column
+pub type Pos = (usize, usize);
+
+// Row-major rectangle of cells
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T: Clone + Default> Grid<T> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            cells: vec![T::default(); rows * cols],
+            rows,
+            cols,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(cells: Vec<T>, cols: usize) -> Self {
+        let rows = cells.len().checked_div(cols).unwrap_or(0);
+        assert_eq!(rows * cols, cells.len(), "Cells must fill whole rows");
+        Self { cells, rows, cols }
+    }
+
+    fn parse_row(
+        row: &str,
+        cells: &mut Vec<T>,
+        cell: &mut impl FnMut(char) -> Option<T>,
+    ) -> ParseResult<()> {
+        for (i, c) in row.char_indices() {
+            match cell(c) {
+                Some(value) => cells.push(value),
+                None => {
+                    let text = &row[i..i + c.len_utf8()];
+                    return Err(ParseError::new(text, format!("Unexpected {c:?}")));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Character map with one row per line, all rows of the same length
+    pub fn parse(inp: &str, mut cell: impl FnMut(char) -> Option<T>) -> ParseResult<Self> {
+        let cols = inp.lines().next().map_or(0, |row| row.chars().count());
+        let mut cells = vec![];
+        for row in inp.lines() {
+            let start = cells.len();
+            Self::parse_row(row, &mut cells, &mut cell)?;
+            if cells.len() - start != cols {
+                return Err(ParseError::new(row, "Rows must have equal length"));
+            }
+        }
+        Ok(Self::from_vec(cells, cols))
+    }
+
+    // Character map whose shorter rows are padded with `fill`
+    pub fn parse_ragged(
+        inp: &str,
+        fill: T,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> ParseResult<Self>
+    where
+        T: Clone,
+    {
+        let cols = inp
+            .lines()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut cells = vec![];
+        for row in inp.lines() {
+            let start = cells.len();
+            Self::parse_row(row, &mut cells, &mut cell)?;
+            cells.resize(start + cols, fill.clone());
+        }
+        Ok(Self::from_vec(cells, cols))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn get(&self, (r, c): Pos) -> Option<&T> {
+        (r < self.rows && c < self.cols).then(|| &self.cells[r * self.cols + c])
+    }
+    pub fn get_mut(&mut self, (r, c): Pos) -> Option<&mut T> {
+        (r < self.rows && c < self.cols).then(|| &mut self.cells[r * self.cols + c])
+    }
+    // Anything outside of the grid, negative coordinates included, is `None`
+    pub fn get_point(&self, p: Point) -> Option<&T> {
+        self.get(p.to_pos()?)
+    }
+
+    // Moves `pos` by `delta`, if it stays within the grid
+    pub fn offset(&self, (r, c): Pos, delta: Point) -> Option<Pos> {
+        let pos = (
+            r.checked_add_signed(delta.y)?,
+            c.checked_add_signed(delta.x)?,
+        );
+        (pos.0 < self.rows && pos.1 < self.cols).then_some(pos)
+    }
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL
+            .iter()
+            .filter_map(move |dir| self.offset(pos, dir.offset()))
+    }
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Point::NEIGHBOURS8
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |r| (0..cols).map(move |c| (r, c)))
+    }
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let i = self.cells.iter().position(pred)?;
+        Some((i / self.cols, i % self.cols))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+    pub fn iter_col(&self, c: usize) -> impl Iterator<Item = &T> {
+        assert!(c < self.cols, "Column {c} out of the grid");
+        self.cells[c..].iter().step_by(self.cols)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    // The cells of the given rows and columns, both clamped to the grid
+    pub fn crop(&self, rows: Range<usize>, cols: Range<usize>) -> Self
+    where
+        T: Clone,
+    {
+        let rows = rows.start.min(self.rows)..rows.end.min(self.rows);
+        let cols = cols.start.min(self.cols)..cols.end.min(self.cols);
+        let width = cols.len();
+        let cells = rows
+            .flat_map(|r| self.row(r)[cols.clone()].iter().cloned())
+            .collect();
+        Grid::from_vec(cells, width)
+    }
+
+    // Adds rows and columns of `fill` around the grid
+    pub fn grow(&mut self, top: usize, bottom: usize, left: usize, right: usize, fill: T)
+    where
+        T: Clone,
+    {
+        if top + bottom + left + right == 0 {
+            return;
+        }
+        let cols = left + self.cols + right;
+        let rows = top + self.rows + bottom;
+        let mut cells = Vec::with_capacity(rows * cols);
+        cells.resize(top * cols, fill.clone());
+        let mut old = std::mem::take(&mut self.cells).into_iter();
+        for _ in 0..self.rows {
+            cells.extend(iter::repeat_n(fill.clone(), left));
+            cells.extend(old.by_ref().take(self.cols));
+            cells.extend(iter::repeat_n(fill.clone(), right));
+        }
+        cells.resize(rows * cols, fill);
+        *self = Self { cells, rows, cols };
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+    fn index(&self, (r, c): Pos) -> &Self::Output {
+        assert!(c < self.cols, "Column {c} out of the grid");
+        &self.cells[r * self.cols + c]
+    }
+}
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (r, c): Pos) -> &mut Self::Output {
+        assert!(c < self.cols, "Column {c} out of the grid");
+        &mut self.cells[r * self.cols + c]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for r in 0..self.rows {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(r) {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::geometry::Point;
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::parse("#..\n.#.", Some).unwrap();
+        assert_eq!(grid.size(), (2, 3));
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(grid.to_string(), "#..\n.#.");
+
+        let err = Grid::parse("#..\n.#", Some).unwrap_err();
+        assert_eq!(err.message, "Rows must have equal length");
+        let grid = Grid::parse_ragged("  #\n.", ' ', Some).unwrap();
+        assert_eq!(grid.to_string(), "  #\n.  ");
+    }
+
+    #[test]
+    fn checked_neighbours() {
+        let grid = Grid::<u8>::new(3, 4);
+        assert_eq!(grid.neighbours4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours4((2, 3)).collect::<Vec<_>>(),
+            [(1, 3), (2, 2)]
+        );
+        assert_eq!(grid.get_point(Point::new(0, -1)), None);
+    }
+
+    #[test]
+    fn grow() {
+        let mut grid = Grid::parse("ab\ncd", Some).unwrap();
+        grid.grow(1, 0, 2, 1, '.');
+        assert_eq!(grid.to_string(), ".....\n..ab.\n..cd.");
+        assert_eq!(grid.position(|&c| c == 'd'), Some((2, 3)));
+        assert_eq!(grid.crop(1..5, 2..4).to_string(), "ab\ncd");
+        assert_eq!(grid.crop(0..1, 4..9).to_string(), ".");
+    }
+}
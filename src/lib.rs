@@ -0,0 +1,20 @@
+pub mod answers;
+pub mod bench;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod cycle;
+pub mod error;
+pub mod frames;
+pub mod gen;
+pub mod geometry;
+pub mod grid;
+pub mod memory;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod serve;
+pub mod solution;
+pub mod stepper;
+pub mod trace;
+pub mod verify;
+pub mod y2022;
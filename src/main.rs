@@ -1,28 +1,465 @@
-mod task25;
-use crate::task25::{prob1, prob2};
-use std::env;
-use std::fs;
-use std::time::Instant;
+#[cfg(feature = "client")]
+use aoc22::client::{self, Client, Verdict};
+use aoc22::runner::{self, DayId, Format, Report};
+#[cfg(feature = "client")]
+use aoc22::solution::Answer;
+use aoc22::solution::Part;
+use aoc22::trace::Filter;
+use aoc22::{bench, frames, gen, scaffold, serve, stepper, verify};
+use json::object;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+use std::{env, fs};
 
-const DAY: u8 = 25;
+// Every allocation goes through the counters of `memory::measure`
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOCATOR: aoc22::memory::Counting = aoc22::memory::Counting;
 
-fn main() {
-    let args: Vec<_> = env::args().collect();
-    let inp = match args[2].as_str() {
-        "main" => {
-            fs::read_to_string(format!("inputs/task{}/main.txt", DAY)).expect("File not found")
+const USAGE: &str = "Usage:
+    aoc22 <day> <part> <main|example|-> [--format <text|json>] [--trace <filter>] [--frames <target>]
+    aoc22 <day> <part> --input <file|directory> [--format <text|json>] [--trace <filter>]
+    aoc22 run-all [--parallel] [--format <text|json>] [--trace <filter>]
+    aoc22 verify [<day>...] [--target <main|example>] [--record]
+    aoc22 bench [<day>...] [--part <part>] [--target <main|example>] [--warmup <n>] [--reps <n>]
+    aoc22 new-day <day>
+    aoc22 gen <day> [--size <n>] [--seed <n>]
+    aoc22 step <day> [<main|example|->|--input <file>] [--delay <ms>]
+    aoc22 serve [--addr <host:port>]
+    aoc22 fetch [<day>...] [--force] [--base-url <url>] [--session <token>]
+    aoc22 submit <day> <part> [<answer>] [--base-url <url>] [--session <token>]
+Every command takes [--year <year>], 2022 by default
+A trace filter is a comma separated list of <level> or <day>=<level|off>, with
+levels info and debug. Solver events go to stderr.
+Frames of simulations go to ascii:<file>, ansi[:<delay in ms>] on stderr, or
+ppm:<directory>[:<pixels per cell>].
+The stepper reads keys from the terminal: n steps, b goes back, space plays and
+pauses every <delay> ms (100 by default), q or Ctrl-C quits.
+The server listens on 127.0.0.1:8022 by default and answers
+POST /day/<day>/part/<part> or /year/<year>/day/<day>/part/<part> with the
+puzzle input as the body.
+fetch downloads missing main inputs and submit sends the answer of the main
+input, solving it unless given. Right answers are added to the answers file.
+The base URL and session token also come from AOC_BASE_URL and AOC_SESSION.
+Built with the count-allocs feature, runs also report allocations and peak heap.
+Every day has a feature, like y2022-day15, in a group per year. All of them are
+on by default, --no-default-features --features y2022-day15 builds just one.";
+
+// Removes `<flag> <value>` from the arguments
+fn take_option(args: &mut Vec<String>, flag: &str) -> Option<String> {
+    let i = args.iter().position(|a| a == flag)?;
+    let value = args.get(i + 1).expect(USAGE).clone();
+    args.drain(i..=i + 1);
+    Some(value)
+}
+
+// Text is the default
+fn take_format(args: &mut Vec<String>) -> Format {
+    take_option(args, "--format").map_or(Format::Text, |f| f.parse().unwrap())
+}
+
+// No tracing by default
+fn take_trace(args: &mut Vec<String>) -> Filter {
+    take_option(args, "--trace").map_or_else(Filter::default, |f| f.parse().unwrap())
+}
+
+fn take_year(args: &mut Vec<String>) -> u16 {
+    take_option(args, "--year").map_or(runner::DEFAULT_YEAR, |y| {
+        y.parse().expect("Year must be a number")
+    })
+}
+
+// Exits with the reason if the day cannot be run
+fn require(id: DayId) {
+    if let Err(e) = runner::available(id) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+// The given days of the year, all of them if there are none
+fn day_ids(year: u16, days: Vec<u8>) -> Vec<DayId> {
+    if days.is_empty() {
+        return DayId::all(year);
+    }
+    days.into_iter().map(|day| DayId::new(year, day)).collect()
+}
+
+fn run_all(args: &[String]) {
+    let mut args = args.to_vec();
+    let format = take_format(&mut args);
+    let filter = take_trace(&mut args);
+    let year = take_year(&mut args);
+    let parallel = args.iter().any(|a| a == "--parallel");
+    let now = Instant::now();
+    let reports = runner::run_all(year, parallel, &filter, format);
+    match format {
+        Format::Text => runner::print_table(&reports, now.elapsed()),
+        Format::Json => reports
+            .iter()
+            .for_each(|r| println!("{}", r.to_json("main").dump())),
+    }
+    let missing: Vec<_> = DayId::all(year)
+        .into_iter()
+        .filter(|&id| runner::available(id).is_err())
+        .map(|id| id.day.to_string())
+        .collect();
+    if !missing.is_empty() {
+        eprintln!("Compiled out: days {}", missing.join(", "));
+    }
+}
+
+fn verify(args: &[String]) {
+    let mut args = args.to_vec();
+    let year = take_year(&mut args);
+    let mut days = vec![];
+    let mut targets = verify::TARGETS.to_vec();
+    let mut record = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--target" => targets = vec![args.next().expect(USAGE).as_str()],
+            "--record" => record = true,
+            day => days.push(day.parse().expect("Day must be a number")),
+        }
+    }
+    if !verify::verify(&day_ids(year, days), &targets, record) {
+        process::exit(1);
+    }
+}
+
+fn bench(args: &[String]) {
+    let mut args = args.to_vec();
+    let year = take_year(&mut args);
+    let mut days = vec![];
+    let mut parts = vec![Part::One, Part::Two];
+    let mut target = "main";
+    let (mut warmup, mut reps) = (1, 10);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => parts = vec![args.next().expect(USAGE).parse().unwrap()],
+            "--target" => target = args.next().expect(USAGE),
+            "--warmup" => warmup = args.next().expect(USAGE).parse().expect(USAGE),
+            "--reps" => reps = args.next().expect(USAGE).parse().expect(USAGE),
+            day => days.push(day.parse().expect("Day must be a number")),
         }
-        "example" => {
-            fs::read_to_string(format!("inputs/task{}/example.txt", DAY)).expect("File not found")
+    }
+    let jobs: Vec<_> = day_ids(year, days)
+        .into_iter()
+        .flat_map(|id| parts.iter().map(move |&part| (id, part)))
+        .collect();
+    bench::bench_all(&jobs, target, warmup, reps);
+}
+
+fn new_day(args: &[String]) {
+    let mut args = args.to_vec();
+    let year = take_year(&mut args);
+    let [day] = args.as_slice() else {
+        panic!("{USAGE}");
+    };
+    let id = DayId::new(year, day.parse().expect("Day must be a number"));
+    if let Err(e) = scaffold::new_day(id) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+    println!(
+        "Created src/y{year}/day{}.rs and {}, rebuild to run it",
+        id.day,
+        id.dir()
+    );
+}
+
+// Random input of the day, the same seed gives the same input
+fn generate(args: &[String]) {
+    let mut args = args.to_vec();
+    let year = take_year(&mut args);
+    let size = take_option(&mut args, "--size").map_or(100, |n| n.parse().expect(USAGE));
+    let seed = take_option(&mut args, "--seed").map_or(0, |n| n.parse().expect(USAGE));
+    let [day] = args.as_slice() else {
+        panic!("{USAGE}");
+    };
+    let id = DayId::new(year, day.parse().expect("Day must be a number"));
+    require(id);
+    match gen::generate(id, size, seed) {
+        Some(inp) => print!("{inp}"),
+        None => {
+            eprintln!("No generator for {id}");
+            process::exit(1);
         }
-        _ => panic!("Unknown target"),
+    }
+}
+
+fn step(args: &[String]) {
+    let mut args = args.to_vec();
+    let year = take_year(&mut args);
+    let delay = take_option(&mut args, "--delay").map_or(100, |n| n.parse().expect(USAGE));
+    let [day, source @ ..] = args.as_slice() else {
+        panic!("{USAGE}");
     };
-    let inp = inp.strip_suffix('\n').unwrap_or(&inp);
-    let now = Instant::now();
-    match args[1].as_str() {
-        "1" => println!("{}", prob1(inp)),
-        "2" => println!("{}", prob2(inp)),
-        _ => panic!("Unknown part"),
+    let id = DayId::new(year, day.parse().expect("Day must be a number"));
+    let inp = match source {
+        [] => runner::read_input(id, "main"),
+        [target] => runner::read_input(id, target),
+        [flag, path] if flag == "--input" => runner::read_file(Path::new(path)),
+        _ => panic!("{USAGE}"),
+    };
+    if let Err(e) = stepper::interactive(id, &inp, Duration::from_millis(delay)) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+fn serve(args: &[String]) {
+    let mut args = args.to_vec();
+    let addr = take_option(&mut args, "--addr").unwrap_or_else(|| "127.0.0.1:8022".to_string());
+    if !args.is_empty() {
+        panic!("{USAGE}");
+    }
+    if let Err(e) = serve::serve(addr.as_str()) {
+        eprintln!("Cannot serve on {addr}: {e}");
+        process::exit(1);
+    }
+}
+
+// The puzzle site from the options or the environment
+#[cfg(feature = "client")]
+fn take_client(args: &mut Vec<String>) -> Client {
+    let base = take_option(args, "--base-url")
+        .or_else(|| env::var("AOC_BASE_URL").ok())
+        .unwrap_or_else(|| client::DEFAULT_BASE.to_string());
+    let Some(session) = take_option(args, "--session").or_else(|| env::var("AOC_SESSION").ok())
+    else {
+        eprintln!("No session token, pass --session or set AOC_SESSION");
+        process::exit(1);
+    };
+    Client::new(client::Config::new(&base, &session))
+}
+
+#[cfg(feature = "client")]
+fn fetch(args: &[String]) {
+    let mut args = args.to_vec();
+    let year = take_year(&mut args);
+    let force = args.iter().any(|a| a == "--force");
+    args.retain(|a| a != "--force");
+    let client = take_client(&mut args);
+    let days = args
+        .iter()
+        .map(|d| d.parse().expect("Day must be a number"));
+    let mut failed = false;
+    for id in day_ids(year, days.collect()) {
+        match client.fetch(id, force) {
+            Ok((path, true)) => println!("{id}: downloaded {}", path.display()),
+            Ok((path, false)) => println!("{id}: cached {}", path.display()),
+            Err(e) => {
+                eprintln!("{id}: {e}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+// The answer to submit when none is given
+#[cfg(feature = "client")]
+fn solve_main(client: &Client, id: DayId, part: Part) -> String {
+    require(id);
+    match runner::run(id, part, &runner::read_file(client.input_path(id))).answer {
+        Ok(Answer::Picture(_)) => {
+            eprintln!("{id} part {part} draws its answer, read it and pass it along");
+            process::exit(1);
+        }
+        Ok(answer) => answer.to_string(),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(feature = "client")]
+fn submit(args: &[String]) {
+    let mut args = args.to_vec();
+    let year = take_year(&mut args);
+    let client = take_client(&mut args);
+    let [day, part, answer @ ..] = args.as_slice() else {
+        panic!("{USAGE}");
+    };
+    let id = DayId::new(year, day.parse().expect("Day must be a number"));
+    let part: Part = part.parse().unwrap();
+    let answer = match answer {
+        [answer] => answer.clone(),
+        [] => solve_main(&client, id, part),
+        _ => panic!("{USAGE}"),
+    };
+    match client.submit(id, part, &answer) {
+        Ok(Verdict::Right) => println!("{answer} is right, recorded"),
+        Ok(Verdict::Wrong(hint)) if hint.is_empty() => println!("{answer} is wrong"),
+        Ok(Verdict::Wrong(hint)) => println!("{answer} is wrong, {hint}"),
+        Ok(Verdict::TooSoon(wait)) => println!("Too soon to submit again. {wait}"),
+        Ok(Verdict::Solved) => println!("{id} part {part} is solved already"),
+        Ok(Verdict::Known(known)) => println!("Not submitted, the answer is known to be {known}"),
+        Ok(Verdict::Unrecognised(text)) => println!("Unexpected response: {text}"),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+// One line per input file, a failing file does not stop the batch
+fn run_batch(id: DayId, part: Part, dir: &Path, format: Format, filter: &Filter) {
+    for path in runner::batch_files(dir) {
+        let name = path.file_name().unwrap().to_string_lossy();
+        let failure = |error: &str| {
+            object! {
+                year: id.year,
+                day: id.day,
+                part: u8::from(part),
+                input: name.as_ref(),
+                answer: null,
+                error: error,
+            }
+        };
+        let inp = match fs::read_to_string(&path) {
+            Ok(inp) => runner::normalize(inp),
+            Err(e) if format == Format::Json => {
+                println!("{}", failure(&e.to_string()).dump());
+                continue;
+            }
+            Err(e) => {
+                println!("{name}: failed, {e}");
+                continue;
+            }
+        };
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            runner::run_traced(id, part, &inp, filter, format)
+        }));
+        if format == Format::Json {
+            let value = match result {
+                Ok(report) => report.to_json(&name),
+                Err(_) => failure("solver panicked"),
+            };
+            println!("{}", value.dump());
+            continue;
+        }
+        match result {
+            Ok(Report {
+                answer: Ok(answer), ..
+            }) => {
+                let answer = answer.to_string();
+                println!("{name}: {}", answer.lines().collect::<Vec<_>>().join(" "));
+            }
+            Ok(Report { answer: Err(e), .. }) => {
+                println!("{name}: failed, {}", e.to_string().lines().next().unwrap())
+            }
+            Err(_) => println!("{name}: failed"),
+        }
+    }
+}
+
+// Runs the day with its frames going to `target`
+fn run_with_frames(
+    id: DayId,
+    part: Part,
+    target: &frames::Target,
+    run: impl FnOnce() -> Report,
+) -> Report {
+    let sink = target.open().unwrap_or_else(|e| {
+        eprintln!("Cannot write frames: {e}");
+        process::exit(1);
+    });
+    let (report, written) = frames::capture(sink, run);
+    match written {
+        Ok(0) => eprintln!("{id} part {part} has no frames to show"),
+        Ok(_) => {}
+        Err(e) => eprintln!("Writing frames stopped: {e}"),
+    }
+    report
+}
+
+fn run_one(args: &[String]) {
+    let mut args = args.to_vec();
+    let format = take_format(&mut args);
+    let filter = take_trace(&mut args);
+    let frames = take_option(&mut args, "--frames").map(|t| t.parse::<frames::Target>().unwrap());
+    let year = take_year(&mut args);
+    let [day, part, source @ ..] = args.as_slice() else {
+        panic!("{USAGE}");
+    };
+    let id = DayId::new(year, day.parse().expect("Day must be a number"));
+    require(id);
+    let part: Part = part.parse().unwrap();
+    let (inp, name) = match source {
+        [flag, path] if flag == "--input" => {
+            let path = Path::new(path);
+            if path.is_dir() {
+                assert!(frames.is_none(), "Frames are shown for single inputs only");
+                return run_batch(id, part, path, format, &filter);
+            }
+            (runner::read_file(path), path.to_string_lossy())
+        }
+        [target] => (runner::read_input(id, target), target.into()),
+        _ => panic!("{USAGE}"),
+    };
+    let run = || runner::run_traced(id, part, &inp, &filter, format);
+    let report = match &frames {
+        Some(target) => run_with_frames(id, part, target, run),
+        None => run(),
+    };
+    match format {
+        Format::Text => print_report(&report),
+        Format::Json => {
+            println!("{}", report.to_json(&name).dump());
+            if report.answer.is_err() {
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn print_report(report: &Report) {
+    match &report.answer {
+        Ok(answer) => println!("{answer}"),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+    println!(
+        "Time spent: {:.2?} (parse {:.2?}, solve {:.2?})",
+        report.elapsed(),
+        report.parse_time,
+        report.solve_time
+    );
+    if let Some(memory) = report.memory {
+        println!("Memory: {memory}");
+    }
+}
+
+fn main() {
+    let args: Vec<_> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("run-all") => run_all(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("new-day") => new_day(&args[1..]),
+        Some("gen") => generate(&args[1..]),
+        Some("step") => step(&args[1..]),
+        Some("serve") => serve(&args[1..]),
+        #[cfg(feature = "client")]
+        Some("fetch") => fetch(&args[1..]),
+        #[cfg(feature = "client")]
+        Some("submit") => submit(&args[1..]),
+        #[cfg(not(feature = "client"))]
+        Some(command @ ("fetch" | "submit")) => {
+            eprintln!("{command} was compiled out, build with --features client");
+            process::exit(1);
+        }
+        _ => run_one(&args),
     }
-    println!("Time spent: {:.2?}", now.elapsed());
 }
@@ -0,0 +1,191 @@
+use std::fmt::{self, Display};
+
+// Heap use of a run, counted by the allocator of the `count-allocs` feature
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    // Every allocation counts, also the ones freed again
+    pub bytes: u64,
+    // Most bytes in use at once
+    pub peak: u64,
+}
+
+pub fn size(bytes: u64) -> String {
+    match bytes {
+        0..=9_999 => format!("{bytes} B"),
+        10_000..=9_999_999 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            size(self.bytes),
+            size(self.peak)
+        )
+    }
+}
+
+#[cfg(feature = "count-allocs")]
+mod counting {
+    use super::Usage;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    // Counts of the current thread. `live` goes below zero when memory of
+    // another thread or from before the measurement is freed.
+    #[derive(Clone, Copy)]
+    pub struct Counts {
+        allocations: u64,
+        bytes: u64,
+        live: i64,
+        peak: i64,
+    }
+
+    const ZERO: Counts = Counts {
+        allocations: 0,
+        bytes: 0,
+        live: 0,
+        peak: 0,
+    };
+
+    thread_local! {
+        static COUNTS: Cell<Counts> = const { Cell::new(ZERO) };
+    }
+
+    fn update(f: impl FnOnce(&mut Counts)) {
+        // Threads that are shutting down are not counted
+        let _ = COUNTS.try_with(|counts| {
+            let mut current = counts.get();
+            f(&mut current);
+            counts.set(current);
+        });
+    }
+
+    fn allocated(size: usize) {
+        update(|counts| {
+            counts.allocations += 1;
+            counts.bytes += size as u64;
+            counts.live += size as i64;
+            counts.peak = counts.peak.max(counts.live);
+        });
+    }
+
+    fn freed(size: usize) {
+        update(|counts| counts.live -= size as i64);
+    }
+
+    // Becomes the global allocator of the binary, and of the tests
+    pub struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            freed(layout.size());
+        }
+        // A move to a bigger block counts as one more allocation
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new
+        }
+    }
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+        let outer = COUNTS.with(|counts| counts.replace(ZERO));
+        let value = f();
+        let inner = COUNTS.with(|counts| counts.get());
+        // The outer measurement sees everything of the inner one
+        COUNTS.with(|counts| {
+            counts.set(Counts {
+                allocations: outer.allocations + inner.allocations,
+                bytes: outer.bytes + inner.bytes,
+                live: outer.live + inner.live,
+                peak: outer.peak.max(outer.live + inner.peak),
+            })
+        });
+        let usage = Usage {
+            allocations: inner.allocations,
+            bytes: inner.bytes,
+            peak: inner.peak.max(0) as u64,
+        };
+        (value, Some(usage))
+    }
+}
+
+// Runs `f` and tells how it used the heap of its thread, `None` unless built
+// with the `count-allocs` feature
+#[cfg(feature = "count-allocs")]
+pub use counting::{measure, Counting};
+
+#[cfg(not(feature = "count-allocs"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    (f(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{measure, Usage};
+
+    #[cfg(feature = "count-allocs")]
+    #[global_allocator]
+    static ALLOCATOR: super::Counting = super::Counting;
+
+    #[test]
+    fn measures_when_counting() {
+        let (len, usage) = measure(|| {
+            let kept = vec![0u8; 4000];
+            let (_, inner) = measure(|| vec![1u8; 1000].len());
+            drop(vec![0u8; 2000]);
+            (kept.len(), inner)
+        });
+        let (len, inner) = len;
+        assert_eq!(len, 4000);
+        if cfg!(feature = "count-allocs") {
+            let (usage, inner) = (usage.unwrap(), inner.unwrap());
+            assert_eq!(
+                (inner.allocations, inner.bytes, inner.peak),
+                (1, 1000, 1000)
+            );
+            assert!(usage.allocations >= 3);
+            assert!(usage.bytes >= 7000);
+            assert!((6000..7000).contains(&usage.peak));
+        } else {
+            assert_eq!((usage, inner), (None, None));
+        }
+    }
+
+    #[test]
+    fn shows_sizes() {
+        let usage = Usage {
+            allocations: 3,
+            bytes: 20_480,
+            peak: 512,
+        };
+        assert_eq!(
+            usage.to_string(),
+            "3 allocations, 20.0 KiB allocated, 512 B peak"
+        );
+    }
+}
@@ -0,0 +1,358 @@
+use crate::error::ParseError;
+use crate::memory::{self, Usage};
+use crate::solution::{Answer, Outcome, Part};
+use crate::trace::{self, Filter};
+use crate::y2022;
+use json::{object, JsonValue};
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+pub type Runner = fn(&str, Part) -> Result<Outcome, ParseError>;
+
+pub const DEFAULT_YEAR: u16 = 2022;
+
+// Day N of a year is at index N - 1, `None` if its feature is off
+pub const YEARS: [(u16, &[Option<Runner>]); 1] = [(2022, &y2022::DAYS)];
+
+pub fn days(year: u16) -> &'static [Option<Runner>] {
+    YEARS
+        .iter()
+        .find(|&&(y, _)| y == year)
+        .map_or(&[], |&(_, days)| days)
+}
+
+// Cargo feature that compiles the day in
+pub fn feature(id: DayId) -> String {
+    format!("y{}-day{}", id.year, id.day)
+}
+
+// Why the day cannot be run, if it cannot
+pub fn available(id: DayId) -> Result<(), String> {
+    match days(id.year).get((id.day as usize).wrapping_sub(1)) {
+        Some(Some(_)) => Ok(()),
+        Some(None) => Err(format!(
+            "{id} was compiled out, build with --features {}",
+            feature(id)
+        )),
+        None => Err(format!("Unknown day {id}")),
+    }
+}
+
+// A puzzle of one event year
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayId {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl DayId {
+    pub fn new(year: u16, day: u8) -> Self {
+        Self { year, day }
+    }
+
+    // Every day of the year
+    pub fn all(year: u16) -> Vec<Self> {
+        (1..=days(year).len() as u8)
+            .map(|day| Self::new(year, day))
+            .collect()
+    }
+
+    // Bundled inputs and expected answers
+    pub fn dir(&self) -> String {
+        format!("inputs/{}/task{}", self.year, self.day)
+    }
+}
+
+impl Display for DayId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {:>2}", self.year, self.day)
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub id: DayId,
+    pub part: Part,
+    pub answer: Result<Answer, ParseError>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    // Parsing and solving together, with the `count-allocs` feature
+    pub memory: Option<Usage>,
+}
+
+impl Report {
+    pub fn elapsed(&self) -> Duration {
+        self.parse_time + self.solve_time
+    }
+
+    // `input` names where the puzzle input came from: a bundled target, `-` or a file
+    pub fn to_json(&self, input: &str) -> JsonValue {
+        let mut value = object! {
+            year: self.id.year,
+            day: self.id.day,
+            part: u8::from(self.part),
+            input: input,
+            answer: JsonValue::Null,
+            parse_time_ns: self.parse_time.as_nanos() as u64,
+            solve_time_ns: self.solve_time.as_nanos() as u64,
+        };
+        match &self.answer {
+            Ok(Answer::Number(n)) => value["answer"] = (*n).into(),
+            Ok(Answer::Text(s)) => value["answer"] = s.as_str().into(),
+            Ok(Answer::Picture(rows)) => value["answer"] = rows.clone().into(),
+            Err(e) => value["error"] = e.to_string().into(),
+        }
+        if let Some(memory) = self.memory {
+            value["allocations"] = memory.allocations.into();
+            value["allocated_bytes"] = memory.bytes.into();
+            value["peak_bytes"] = memory.peak.into();
+        }
+        value
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    // One JSON object per line
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Unknown format {s}")),
+        }
+    }
+}
+
+// The final newline and Windows line endings are not part of the puzzle
+pub fn normalize(inp: String) -> String {
+    let inp = if inp.contains('\r') {
+        inp.replace("\r\n", "\n")
+    } else {
+        inp
+    };
+    match inp.strip_suffix('\n') {
+        Some(stripped) => stripped.to_string(),
+        None => inp,
+    }
+}
+
+pub fn read_file(path: impl AsRef<Path>) -> String {
+    normalize(fs::read_to_string(path).expect("File not found"))
+}
+
+pub fn read_stdin() -> String {
+    let mut inp = String::new();
+    io::stdin()
+        .read_to_string(&mut inp)
+        .expect("Cannot read stdin");
+    normalize(inp)
+}
+
+// Bundled inputs by name, or `-` for stdin
+pub fn read_input(id: DayId, target: &str) -> String {
+    match target {
+        "main" | "example" => read_file(format!("{}/{target}.txt", id.dir())),
+        "-" => read_stdin(),
+        _ => panic!("Unknown target"),
+    }
+}
+
+// Regular files of a directory, sorted by name
+pub fn batch_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<_> = fs::read_dir(dir)
+        .expect("Cannot read directory")
+        .map(|entry| entry.expect("Cannot read directory").path())
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+    files
+}
+
+// Days that are not `available` panic
+pub fn run(id: DayId, part: Part, inp: &str) -> Report {
+    let Some(&Some(runner)) = days(id.year).get((id.day as usize).wrapping_sub(1)) else {
+        panic!("{}", available(id).unwrap_err());
+    };
+    let (outcome, memory) = memory::measure(|| runner(inp, part));
+    match outcome {
+        Ok(Outcome {
+            answer,
+            parse_time,
+            solve_time,
+        }) => Report {
+            id,
+            part,
+            answer: Ok(answer),
+            parse_time,
+            solve_time,
+            memory,
+        },
+        Err(e) => Report {
+            id,
+            part,
+            answer: Err(e.in_day(id.day)),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            memory,
+        },
+    }
+}
+
+// Like `run`, with the events of the solver going to stderr at the level the
+// filter gives to the day
+pub fn run_traced(id: DayId, part: Part, inp: &str, filter: &Filter, format: Format) -> Report {
+    match filter.level(id.day) {
+        Some(level) => trace::observe(level, Box::new(trace::Log::new(id, part, format)), || {
+            run(id, part, inp)
+        }),
+        None => run(id, part, inp),
+    }
+}
+
+// Both parts of every day that was compiled in
+fn jobs(year: u16) -> Vec<(DayId, Part)> {
+    DayId::all(year)
+        .into_iter()
+        .filter(|&id| available(id).is_ok())
+        .flat_map(|id| [(id, Part::One), (id, Part::Two)])
+        .collect()
+}
+
+pub fn run_all(year: u16, parallel: bool, filter: &Filter, format: Format) -> Vec<Report> {
+    let jobs = jobs(year);
+    let run = |id, part| run_traced(id, part, &read_input(id, "main"), filter, format);
+    if !parallel {
+        return jobs.into_iter().map(|(id, part)| run(id, part)).collect();
+    }
+
+    let workers = thread::available_parallelism().map_or(1, |n| n.get());
+    let next = AtomicUsize::new(0);
+    let reports = Mutex::new(Vec::with_capacity(jobs.len()));
+    thread::scope(|s| {
+        for _ in 0..workers {
+            s.spawn(|| {
+                while let Some(&(id, part)) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let report = run(id, part);
+                    reports.lock().unwrap().push(report);
+                }
+            });
+        }
+    });
+    let mut reports = reports.into_inner().unwrap();
+    reports.sort_by_key(|r| (r.id, r.part));
+    reports
+}
+
+fn cell(report: &Report) -> String {
+    match &report.answer {
+        Ok(answer) => answer.to_string(),
+        Err(_) => "parse error".to_string(),
+    }
+}
+
+pub fn print_table(reports: &[Report], wall: Duration) {
+    let width = reports
+        .iter()
+        .flat_map(|r| cell(r).lines().map(str::len).collect::<Vec<_>>())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+    // Allocations and peak heap next to the time, when they are counted
+    let memory = |r: &Report| {
+        r.memory.map_or(String::new(), |m| {
+            format!(" | {:>10} | {:>10}", m.allocations, memory::size(m.peak))
+        })
+    };
+    let (time, extra) = match reports.iter().any(|r| r.memory.is_some()) {
+        true => (
+            format!("{:<10} | {:>10} | {:>10}", "Time", "Allocs", "Peak"),
+            26,
+        ),
+        false => ("Time".to_string(), 0),
+    };
+    println!("Day | Part | {:<width$} | {time}", "Answer");
+    println!("{}", "-".repeat(width + 24 + extra));
+    for r in reports {
+        let answer = cell(r);
+        let mut lines = answer.lines();
+        let first = lines.next().unwrap_or("");
+        println!(
+            "{:>3} | {:>4} | {first:<width$} | {:>10.2?}{}",
+            r.id.day,
+            r.part,
+            r.elapsed(),
+            memory(r)
+        );
+        // Pictures continue on the following rows
+        for line in lines {
+            println!("    |      | {line:<width$} |");
+        }
+    }
+    println!("{}", "-".repeat(width + 24 + extra));
+    let total: Duration = reports.iter().map(Report::elapsed).sum();
+    println!("Wall time: {wall:.2?}");
+    println!("CPU time (sum of runs): {total:.2?}");
+    for r in reports {
+        if let Err(e) = &r.answer {
+            println!("\n{e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{available, days, feature, run, DayId, DEFAULT_YEAR};
+    use crate::solution::Part;
+
+    #[test]
+    fn json_report() {
+        let id = DayId::new(DEFAULT_YEAR, 1);
+        if available(id).is_err() {
+            return;
+        }
+        let mut value = run(id, Part::Two, "1\n2\n\n3\n\n4").to_json("inline");
+        assert!(value.remove("parse_time_ns").is_number());
+        assert!(value.remove("solve_time_ns").is_number());
+        // Counted with the `count-allocs` feature only
+        for field in ["allocations", "allocated_bytes", "peak_bytes"] {
+            assert_eq!(
+                value.remove(field).is_number(),
+                cfg!(feature = "count-allocs")
+            );
+        }
+        assert_eq!(
+            value.dump(),
+            r#"{"year":2022,"day":1,"part":2,"input":"inline","answer":10}"#
+        );
+
+        let value = run(id, Part::One, "1\nx").to_json("inline");
+        assert!(value["answer"].is_null());
+        assert!(value["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("day 1: line 2"));
+    }
+
+    #[test]
+    fn available_days() {
+        let id = DayId::new(DEFAULT_YEAR, 15);
+        assert_eq!(feature(id), "y2022-day15");
+        assert_eq!(available(id).is_ok(), cfg!(feature = "y2022-day15"));
+        let next = DayId::new(DEFAULT_YEAR, days(DEFAULT_YEAR).len() as u8 + 1);
+        assert_eq!(available(next), Err(format!("Unknown day {next}")));
+        assert!(available(DayId::new(1999, 1)).is_err());
+    }
+}
@@ -0,0 +1,336 @@
+use crate::answers::{Answers, PLACEHOLDER};
+use crate::runner::{self, DayId};
+use crate::solution::Part;
+use crate::verify::TARGETS;
+use regex::Regex;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const LIB: &str = "src/lib.rs";
+const MANIFEST: &str = "Cargo.toml";
+const RUNNER: &str = "src/runner.rs";
+const WIDTH: usize = 100;
+
+// Example tests are ignored until their answers are filled in
+const TEMPLATE: &str = r#"use crate::error::ParseResult;
+
+pub fn parse(inp: &str) -> ParseResult<Vec<String>> {
+    Ok(inp.lines().map(str::to_string).collect())
+}
+
+pub fn part1(lines: &[String]) -> usize {
+    lines.len()
+}
+
+pub fn part2(lines: &[String]) -> usize {
+    lines.len()
+}
+
+crate::solution::solution!(Vec<String>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    #[ignore = "example answer unknown"]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("{dir}/example.txt").unwrap()).unwrap()),
+            0,
+        );
+    }
+
+    #[test]
+    #[ignore = "example answer unknown"]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("{dir}/example.txt").unwrap()).unwrap()),
+            0,
+        );
+    }
+}
+"#;
+
+pub fn module(id: DayId) -> String {
+    TEMPLATE.replace("{dir}", &id.dir())
+}
+
+// Module of a year with its first `days` days, each behind its feature
+pub fn year_module(year: u16, days: u8) -> String {
+    let mut ids: Vec<_> = (1..=days).map(|day| DayId::new(year, day)).collect();
+    ids.sort_by_key(|id| format!("day{}", id.day));
+    let mut source = String::from("use crate::runner::Runner;\nuse crate::solution;\n\n");
+    for id in ids {
+        source += &format!(
+            "#[cfg(feature = \"{}\")]\npub mod day{};\n",
+            runner::feature(id),
+            id.day
+        );
+    }
+    let entries: Vec<_> = (1..=days)
+        .map(|day| {
+            let feature = runner::feature(DayId::new(year, day));
+            format!("solution::enabled!(\"{feature}\", solution::run::<day{day}::Solver>)")
+        })
+        .collect();
+    let header = format!("pub const DAYS: [Option<Runner>; {days}] = [");
+    let table = format!("{header}{}];", entries.join(", "));
+    if table.len() <= WIDTH {
+        return format!("{source}\n{table}\n");
+    }
+    format!("{source}\n{header}\n    {},\n];\n", entries.join(",\n    "))
+}
+
+// Adds the feature of a new day to the manifest, in the group of its year.
+// The first day of a year adds the group to `all` as well.
+fn register_feature(manifest: &str, id: DayId) -> Result<String, String> {
+    let broken = || "Unexpected layout of the features in the manifest".to_string();
+    let (group, feature) = (format!("y{}", id.year), runner::feature(id));
+    let mut lines: Vec<String> = manifest.lines().map(str::to_string).collect();
+    let group_start = format!("{group} = [");
+    let day_prefix = format!("{group}-day");
+    match lines.iter().position(|line| *line == group_start) {
+        Some(start) => {
+            let end = start
+                + lines[start..]
+                    .iter()
+                    .position(|l| l == "]")
+                    .ok_or_else(broken)?;
+            lines.insert(end, format!("    \"{feature}\","));
+            let last = lines
+                .iter()
+                .rposition(|line| line.starts_with(&day_prefix))
+                .ok_or_else(broken)?;
+            lines.insert(last + 1, format!("{feature} = []"));
+        }
+        None => {
+            let all = lines
+                .iter()
+                .position(|line| line.starts_with("all = ["))
+                .ok_or_else(broken)?;
+            let groups = lines[all]
+                .strip_prefix("all = [")
+                .and_then(|rest| rest.strip_suffix(']'))
+                .ok_or_else(broken)?;
+            let mut groups: Vec<_> = groups.split(", ").filter(|g| !g.is_empty()).collect();
+            let quoted = format!("\"{group}\"");
+            groups.push(&quoted);
+            groups.sort();
+            lines[all] = format!("all = [{}]", groups.join(", "));
+            // After the features of the latest year
+            let last = lines
+                .iter()
+                .rposition(|line| line.starts_with('y') && line.contains("-day"))
+                .ok_or_else(broken)?;
+            let block = [group_start, format!("    \"{feature}\","), "]".to_string()];
+            let at = last + 1;
+            lines.splice(at..at, block.into_iter().chain([format!("{feature} = []")]));
+        }
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+// Adds `pub mod <name>;` keeping the declarations sorted
+fn register_module(lib: &str, name: &str) -> String {
+    let decl = format!("pub mod {name};");
+    let mut lines: Vec<_> = lib.lines().collect();
+    let at = lines
+        .iter()
+        .position(|line| {
+            line.strip_prefix("pub mod ")
+                .and_then(|rest| rest.strip_suffix(';'))
+                .is_some_and(|module| module > name)
+        })
+        .unwrap_or(lines.len());
+    lines.insert(at, &decl);
+    lines.join("\n") + "\n"
+}
+
+// Imports the year module and adds it to `YEARS`
+fn register_year(source: &str, year: u16) -> Result<String, String> {
+    let broken = || "Unexpected layout of the runner".to_string();
+    let start = source.find("pub const YEARS: ").ok_or_else(broken)?;
+    let end = start + source[start..].find("];").ok_or_else(broken)? + "];".len();
+    let entry = Regex::new(r"\((\d+), &y\d+::DAYS\)").unwrap();
+    let mut years: Vec<u16> = entry
+        .captures_iter(&source[start..end])
+        .map(|c| c[1].parse().unwrap())
+        .collect();
+    years.push(year);
+    years.sort();
+    let entries: Vec<_> = years
+        .iter()
+        .map(|y| format!("({y}, &y{y}::DAYS)"))
+        .collect();
+    let header = format!(
+        "pub const YEARS: [(u16, &[Option<Runner>]); {}] = [",
+        years.len()
+    );
+    let mut table = format!("{header}{}];", entries.join(", "));
+    if table.len() > WIDTH {
+        table = format!("{header}\n    {},\n];", entries.join(",\n    "));
+    }
+    let mut lines: Vec<_> = format!("{}{table}{}", &source[..start], &source[end..])
+        .lines()
+        .map(str::to_string)
+        .collect();
+    let import = format!("use crate::y{year};");
+    let last = lines
+        .iter()
+        .rposition(|line| line.starts_with("use crate::y"))
+        .ok_or_else(broken)?;
+    let at = lines
+        .iter()
+        .position(|line| line.starts_with("use crate::y") && *line > import)
+        .unwrap_or(last + 1);
+    lines.insert(at, import);
+    Ok(lines.join("\n") + "\n")
+}
+
+// Creates the module and the inputs of the next day of a year, and registers
+// them. The first day of a year registers the year as well.
+pub fn new_day(id: DayId) -> Result<(), String> {
+    let DayId { year, day } = id;
+    let next = runner::days(year).len() + 1;
+    let module_path = format!("src/y{year}/day{day}.rs");
+    if (day as usize) < next || Path::new(&module_path).exists() || Path::new(&id.dir()).exists() {
+        return Err(format!("{id} exists already"));
+    }
+    if day as usize != next {
+        return Err(format!("Days are added in order, the next one is {next}"));
+    }
+    let year_path = format!("src/y{year}.rs");
+    let io = |e: io::Error| e.to_string();
+    let manifest = register_feature(&fs::read_to_string(MANIFEST).map_err(io)?, id)?;
+    let mut registry = None;
+    if next == 1 {
+        if Path::new(&year_path).exists() {
+            return Err(format!("{year_path} exists but the year is not registered"));
+        }
+        let lib = register_module(&fs::read_to_string(LIB).map_err(io)?, &format!("y{year}"));
+        let runner = register_year(&fs::read_to_string(RUNNER).map_err(io)?, year)?;
+        registry = Some((lib, runner));
+    }
+
+    fs::create_dir_all(id.dir()).map_err(io)?;
+    let mut answers = Answers::default();
+    for target in TARGETS {
+        fs::write(format!("{}/{target}.txt", id.dir()), "").map_err(io)?;
+        for part in [Part::One, Part::Two] {
+            answers.set(target, part, PLACEHOLDER.to_string());
+        }
+    }
+    answers.save(id).map_err(io)?;
+    fs::create_dir_all(format!("src/y{year}")).map_err(io)?;
+    fs::write(&module_path, module(id)).map_err(io)?;
+    fs::write(&year_path, year_module(year, day)).map_err(io)?;
+    fs::write(MANIFEST, manifest).map_err(io)?;
+    if let Some((lib, runner)) = registry {
+        fs::write(LIB, lib).map_err(io)?;
+        fs::write(RUNNER, runner).map_err(io)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{register_feature, register_module, register_year, year_module};
+    use crate::runner::DayId;
+
+    const YEAR: &str = r#"use crate::runner::Runner;
+use crate::solution;
+
+#[cfg(feature = "y2022-day1")]
+pub mod day1;
+#[cfg(feature = "y2022-day2")]
+pub mod day2;
+
+pub const DAYS: [Option<Runner>; 2] = [
+    solution::enabled!("y2022-day1", solution::run::<day1::Solver>),
+    solution::enabled!("y2022-day2", solution::run::<day2::Solver>),
+];
+"#;
+
+    // The parts of src/runner.rs that `register_year` edits
+    const RUNNER: &str = r#"use crate::solution::{Answer, Outcome, Part};
+use crate::y2022;
+use std::fs;
+
+pub type Runner = fn(&str, Part) -> Result<Outcome, ParseError>;
+
+pub const YEARS: [(u16, &[Option<Runner>]); 1] = [(2022, &y2022::DAYS)];
+"#;
+
+    const MANIFEST: &str = r#"[package]
+name = "aoc22"
+
+[features]
+default = ["all", "client"]
+all = ["y2022"]
+y2022 = [
+    "y2022-day1",
+    "y2022-day2",
+]
+y2022-day1 = []
+y2022-day2 = ["dep:hashbrown"]
+client = ["dep:ureq"]
+
+[dependencies]
+hashbrown = { version = "0.13.1", optional = true }
+"#;
+
+    #[test]
+    fn generates_year_module() {
+        assert_eq!(year_module(2022, 2), YEAR);
+        // Sorted like rustfmt sorts them
+        assert!(year_module(2022, 10)
+            .contains("pub mod day1;\n#[cfg(feature = \"y2022-day10\")]\npub mod day10;\n"));
+    }
+
+    #[test]
+    fn registers_new_year() {
+        let lib = register_module("pub mod bench;\npub mod day1;\npub mod error;\n", "day10");
+        assert_eq!(
+            lib,
+            "pub mod bench;\npub mod day1;\npub mod day10;\npub mod error;\n"
+        );
+
+        let next = register_year(RUNNER, 2023).unwrap();
+        assert!(next.contains("use crate::y2022;\nuse crate::y2023;\nuse std::fs;\n"));
+        assert!(next.contains(
+            "pub const YEARS: [(u16, &[Option<Runner>]); 2] = [(2022, &y2022::DAYS), (2023, &y2023::DAYS)];"
+        ));
+        assert_eq!(next.lines().count(), RUNNER.lines().count() + 1);
+        // Too long for one line
+        let next = register_year(&next, 2021).unwrap();
+        assert_eq!(next.lines().count(), RUNNER.lines().count() + 6);
+        assert!(next.contains("use crate::y2021;\nuse crate::y2022;\nuse crate::y2023;\n"));
+    }
+
+    #[test]
+    fn registers_features() {
+        let next = register_feature(MANIFEST, DayId::new(2022, 3)).unwrap();
+        let day3 = MANIFEST
+            .replace(
+                "    \"y2022-day2\",\n",
+                "    \"y2022-day2\",\n    \"y2022-day3\",\n",
+            )
+            .replace(
+                "y2022-day2 = [\"dep:hashbrown\"]\n",
+                "y2022-day2 = [\"dep:hashbrown\"]\ny2022-day3 = []\n",
+            );
+        assert_eq!(next, day3);
+
+        let next = register_feature(&next, DayId::new(2023, 1)).unwrap();
+        let year = day3
+            .replace("all = [\"y2022\"]", "all = [\"y2022\", \"y2023\"]")
+            .replace(
+                "y2022-day3 = []\n",
+                "y2022-day3 = []\ny2023 = [\n    \"y2023-day1\",\n]\ny2023-day1 = []\n",
+            );
+        assert_eq!(next, year);
+    }
+}
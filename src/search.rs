@@ -0,0 +1,198 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    // From the start to the goal, both included
+    pub states: Vec<S>,
+}
+
+// Visited states are kept in an arena, parents point into it
+fn reconstruct<S: Clone>(states: &[S], parents: &[usize], mut i: usize) -> Vec<S> {
+    let mut path = vec![states[i].clone()];
+    while parents[i] != usize::MAX {
+        i = parents[i];
+        path.push(states[i].clone());
+    }
+    path.reverse();
+    path
+}
+
+// Shortest path when every step costs 1
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut states = vec![start];
+    let mut parents = vec![usize::MAX];
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some((i, cost)) = queue.pop_front() {
+        if is_goal(&states[i]) {
+            let states = reconstruct(&states, &parents, i);
+            return Some(Path { cost, states });
+        }
+        for next in neighbours(&states[i]) {
+            if seen.insert(next.clone()) {
+                queue.push_back((states.len(), cost + 1));
+                states.push(next);
+                parents.push(i);
+            }
+        }
+    }
+    None
+}
+
+// Every state reachable from the start, in the order of distance
+pub fn flood_fill<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> Vec<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut states = vec![start];
+    let mut i = 0;
+    while i < states.len() {
+        for next in neighbours(&states[i]) {
+            if seen.insert(next.clone()) {
+                states.push(next);
+            }
+        }
+        i += 1;
+    }
+    states
+}
+
+// Shortest path with non-negative step costs. The heuristic must never
+// overestimate the remaining cost
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut index = HashMap::from([(start.clone(), 0)]);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut states = vec![start];
+    let mut parents = vec![usize::MAX];
+    let mut costs = vec![C::default()];
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > costs[i] {
+            continue;
+        }
+        if is_goal(&states[i]) {
+            let states = reconstruct(&states, &parents, i);
+            return Some(Path { cost, states });
+        }
+        for (next, step) in neighbours(&states[i]) {
+            let next_cost = cost + step;
+            let j = match index.entry(next) {
+                Entry::Occupied(e) if costs[*e.get()] <= next_cost => continue,
+                Entry::Occupied(e) => {
+                    let j = *e.get();
+                    costs[j] = next_cost;
+                    parents[j] = i;
+                    j
+                }
+                Entry::Vacant(e) => {
+                    let j = states.len();
+                    states.push(e.key().clone());
+                    parents.push(i);
+                    costs.push(next_cost);
+                    e.insert(j);
+                    j
+                }
+            };
+            heap.push(Reverse((next_cost + heuristic(&states[j]), next_cost, j)));
+        }
+    }
+    None
+}
+
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra, flood_fill};
+    use crate::grid::{Grid, Pos};
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.";
+
+    fn maze() -> Grid<bool> {
+        Grid::parse(MAZE, |c| Some(c == '#')).unwrap()
+    }
+
+    fn open(grid: &Grid<bool>, pos: Pos) -> Vec<Pos> {
+        grid.neighbours4(pos).filter(|&p| !grid[p]).collect()
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let grid = maze();
+        let goal = (0, 6);
+        let path = bfs((0, 0), |&p| open(&grid, p), |&p| p == goal).unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.states.len(), 13);
+        assert_eq!(path.states[..4], [(0, 0), (1, 0), (2, 0), (2, 1)]);
+        assert!(path
+            .states
+            .windows(2)
+            .all(|w| grid.neighbours4(w[0]).any(|p| p == w[1])));
+
+        let unit = |&p: &Pos| open(&grid, p).into_iter().map(|p| (p, 1));
+        let manhattan = |&(r, c): &Pos| r.abs_diff(goal.0) + c.abs_diff(goal.1);
+        let found = dijkstra((0, 0), unit, |&p| p == goal).unwrap();
+        assert_eq!(found.cost, 12);
+        let found = astar((0, 0), unit, manhattan, |&p| p == goal).unwrap();
+        assert_eq!(found, path);
+
+        // Walls are never entered
+        assert_eq!(bfs((0, 0), |&p| open(&grid, p), |&p| p == (1, 1)), None);
+    }
+
+    #[test]
+    fn weighted() {
+        // Going around is cheaper than the direct edge
+        let edges = [(0, 1, 10), (0, 2, 1), (2, 3, 1), (3, 1, 1)];
+        let next = |&n: &u8| edges.iter().filter(move |e| e.0 == n).map(|e| (e.1, e.2));
+        let path = dijkstra(0, next, |&n| n == 1).unwrap();
+        assert_eq!((path.cost, path.states), (3, vec![0, 2, 3, 1]));
+    }
+
+    #[test]
+    fn fill() {
+        let grid = maze();
+        let reached = flood_fill((0, 0), |&p| open(&grid, p));
+        assert_eq!(reached.len(), grid.iter().filter(|&&wall| !wall).count());
+        assert_eq!(reached[0], (0, 0));
+    }
+}
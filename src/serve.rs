@@ -0,0 +1,287 @@
+use crate::runner::{self, DayId, DEFAULT_YEAR};
+use crate::solution::Part;
+use json::{object, JsonValue};
+use std::any::Any;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::Duration;
+
+// Larger puzzle inputs are refused
+const MAX_BODY: usize = 16 << 20;
+// Clients that stop sending in the middle of a request are dropped
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: JsonValue,
+}
+
+impl Response {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: object! { error: message.into() },
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            411 => "Length Required",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            501 => "Not Implemented",
+            _ => "Internal Server Error",
+        }
+    }
+
+    fn write(&self, out: &mut impl Write) -> io::Result<()> {
+        let body = self.body.dump();
+        write!(
+            out,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            self.status,
+            self.reason(),
+            body.len()
+        )?;
+        out.flush()
+    }
+}
+
+// One request with a `Content-Length` body, chunked bodies are not supported
+pub(crate) fn read_request(stream: impl Read) -> Result<Request, Response> {
+    let bad = |e: io::Error| Response::error(400, e.to_string());
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(bad)?;
+    let mut words = line.split_whitespace();
+    let (Some(method), Some(path)) = (words.next(), words.next()) else {
+        return Err(Response::error(400, "Malformed request line"));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+    let mut headers = vec![];
+    loop {
+        line.clear();
+        reader.read_line(&mut line).map_err(bad)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    let mut request = Request {
+        method,
+        path,
+        headers,
+        body: vec![],
+    };
+    let length = match request.header("content-length") {
+        Some(value) => Some(
+            value
+                .parse::<usize>()
+                .map_err(|_| Response::error(400, "Bad Content-Length"))?,
+        ),
+        None => None,
+    };
+    request.body = match length {
+        Some(n) if n > MAX_BODY => return Err(Response::error(413, "Input too large")),
+        Some(n) => {
+            let mut body = vec![0; n];
+            reader.read_exact(&mut body).map_err(bad)?;
+            body
+        }
+        None if request.method == "POST" => {
+            return Err(Response::error(411, "Missing Content-Length"))
+        }
+        None => vec![],
+    };
+    Ok(request)
+}
+
+// `/day/<n>/part/<p>` for the default year, or `/year/<y>/day/<n>/part/<p>`
+fn route(path: &str) -> Option<(DayId, Part)> {
+    let path = path.split('?').next().unwrap_or(path);
+    let segments: Vec<_> = path.trim_matches('/').split('/').collect();
+    let (year, day, part) = match segments.as_slice() {
+        ["day", day, "part", part] => (DEFAULT_YEAR, day, part),
+        ["year", year, "day", day, "part", part] => (year.parse().ok()?, day, part),
+        _ => return None,
+    };
+    Some((DayId::new(year, day.parse().ok()?), part.parse().ok()?))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "solver panicked".to_string(),
+    }
+}
+
+pub fn handle(request: &Request) -> Response {
+    let Some((id, part)) = route(&request.path) else {
+        return Response::error(404, format!("No route for {}", request.path));
+    };
+    if request.method != "POST" {
+        return Response::error(405, "Puzzles are solved with POST");
+    }
+    if let Err(e) = runner::available(id) {
+        // Days that were compiled out exist, they cannot be solved here
+        let exists = (1..=runner::days(id.year).len()).contains(&(id.day as usize));
+        return Response::error(if exists { 501 } else { 404 }, e);
+    }
+    let Ok(inp) = String::from_utf8(request.body.clone()) else {
+        return Response::error(400, "Input is not UTF-8");
+    };
+    let inp = runner::normalize(inp);
+    match panic::catch_unwind(AssertUnwindSafe(|| runner::run(id, part, &inp))) {
+        Ok(report) => Response {
+            status: if report.answer.is_ok() { 200 } else { 422 },
+            body: report.to_json("body"),
+        },
+        Err(payload) => Response::error(500, panic_message(payload.as_ref())),
+    }
+}
+
+fn respond(mut stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let response = match read_request(&stream) {
+        Ok(request) => handle(&request),
+        Err(response) => response,
+    };
+    response.write(&mut stream)
+}
+
+// Answers every connection on its own thread, forever
+pub fn serve_on(listener: TcpListener) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            if let Err(e) = respond(stream) {
+                eprintln!("Cannot respond: {e}");
+            }
+        });
+    }
+    Ok(())
+}
+
+pub fn serve(addr: impl ToSocketAddrs) -> io::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    eprintln!("Listening on http://{}", listener.local_addr()?);
+    serve_on(listener)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{route, serve_on};
+    use crate::runner::{self, DayId};
+    use crate::solution::Part;
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpListener, TcpStream};
+    use std::thread;
+
+    fn server() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve_on(listener));
+        addr
+    }
+
+    // Status and body of the response
+    fn request(addr: SocketAddr, raw: &str) -> (u16, json::JsonValue) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(raw.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, json::parse(body).unwrap())
+    }
+
+    fn post(addr: SocketAddr, path: &str, body: &str) -> (u16, json::JsonValue) {
+        let raw = format!(
+            "POST {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+        request(addr, &raw)
+    }
+
+    #[test]
+    fn routes() {
+        assert_eq!(
+            route("/day/7/part/2"),
+            Some((DayId::new(2022, 7), Part::Two))
+        );
+        assert_eq!(
+            route("/year/2022/day/25/part/1?x=1"),
+            Some((DayId::new(2022, 25), Part::One))
+        );
+        assert_eq!(route("/day/7/part/3"), None);
+        assert_eq!(route("/day/x/part/1"), None);
+        assert_eq!(route("/"), None);
+    }
+
+    #[test]
+    fn solves_posted_inputs() {
+        if runner::available(DayId::new(2022, 1)).is_err() {
+            return;
+        }
+        let addr = server();
+        let (status, body) = post(addr, "/day/1/part/1", "1000\n2000\n\n4000\n");
+        assert_eq!(status, 200);
+        assert_eq!(body["answer"], 4000);
+        assert_eq!(body["day"], 1);
+        assert!(body["parse_time_ns"].is_number() && body["solve_time_ns"].is_number());
+    }
+
+    #[test]
+    fn reports_errors_and_keeps_serving() {
+        let days = [1, 7].map(|day| runner::available(DayId::new(2022, day)));
+        if days.iter().any(Result::is_err) {
+            return;
+        }
+        let addr = server();
+        let (status, body) = post(addr, "/day/1/part/1", "1000\nelf\n");
+        assert_eq!(status, 422);
+        assert!(body["error"].as_str().unwrap().contains("line 2"));
+        let next = runner::days(2022).len() + 1;
+        assert_eq!(post(addr, &format!("/day/{next}/part/1"), "").0, 404);
+        assert_eq!(post(addr, "/day/1/part/1/x", "").0, 404);
+        assert_eq!(request(addr, "GET /day/1/part/1 HTTP/1.1\r\n\r\n").0, 405);
+        assert_eq!(request(addr, "POST /day/1/part/1 HTTP/1.1\r\n\r\n").0, 411);
+        // No directories at all, the solver panics looking for the root
+        let (status, body) = post(addr, "/day/7/part/2", "");
+        assert_eq!(status, 500);
+        assert!(body["error"].is_string());
+        let inp = runner::read_input(DayId::new(2022, 7), "example");
+        assert_eq!(post(addr, "/day/7/part/2", &inp).0, 200);
+    }
+}
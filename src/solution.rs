@@ -0,0 +1,151 @@
+use crate::error::{ParseError, ParseResult};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    // Multi-line rendered output, like the CRT screen of day 10
+    Picture(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+            Self::Picture(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Self::Number(i64::try_from(value).expect("Answer does not fit into i64"))
+            }
+        })*
+    };
+}
+answer_from_number!(i16, i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Self::Picture(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("Unknown part {s}")),
+        }
+    }
+}
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => f.pad("1"),
+            Self::Two => f.pad("2"),
+        }
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    fn parse(inp: &str) -> ParseResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+pub fn run<S: Solution>(inp: &str, part: Part) -> Result<Outcome, ParseError> {
+    let now = Instant::now();
+    let input = S::parse(inp).map_err(|e| e.locate(inp))?;
+    let parse_time = now.elapsed();
+    let now = Instant::now();
+    let answer = match part {
+        Part::One => S::part1(&input),
+        Part::Two => S::part2(&input),
+    };
+    Ok(Outcome {
+        answer,
+        parse_time,
+        solve_time: now.elapsed(),
+    })
+}
+
+// Implements `Solution` for a day module in terms of its `parse`, `part1` and
+// `part2`. Unused when every day is compiled out.
+#[allow(unused_macros)]
+macro_rules! solution {
+    ($input:ty) => {
+        pub struct Solver;
+
+        impl $crate::solution::Solution for Solver {
+            type Input = $input;
+
+            fn parse(inp: &str) -> $crate::error::ParseResult<Self::Input> {
+                parse(inp)
+            }
+            fn part1(input: &Self::Input) -> $crate::solution::Answer {
+                part1(input).into()
+            }
+            fn part2(input: &Self::Input) -> $crate::solution::Answer {
+                part2(input).into()
+            }
+        }
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use solution;
+
+// `Some(item)` when the feature of its day is on, `None` when the day is
+// compiled out. Every table of days is built from these.
+macro_rules! enabled {
+    ($feature:literal, $item:expr) => {{
+        #[cfg(feature = $feature)]
+        let item = Some($item as _);
+        #[cfg(not(feature = $feature))]
+        let item = None;
+        item
+    }};
+}
+pub(crate) use enabled;
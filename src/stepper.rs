@@ -0,0 +1,321 @@
+use crate::error::ParseResult;
+use crate::frames::{Ansi, Frame, Sink};
+use crate::runner::{self, DayId};
+use crate::solution;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+use std::process::Command as Shell;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+// A simulation that can be watched one step at a time
+pub trait Simulation {
+    // Advances by one step, `false` once there is nothing left to do
+    fn step(&mut self) -> bool;
+    fn frame(&self) -> Frame;
+    fn snapshot(&self) -> Box<dyn Simulation>;
+}
+
+pub type Start = fn(&str) -> ParseResult<Box<dyn Simulation>>;
+
+// Days of a year with a simulation, `None` if the day was compiled out
+type Days = &'static [(u8, Option<Start>)];
+
+const Y2022: [(u8, Option<Start>); 2] = [
+    (
+        23,
+        solution::enabled!("y2022-day23", crate::y2022::day23::simulation),
+    ),
+    (
+        24,
+        solution::enabled!("y2022-day24", crate::y2022::day24::simulation),
+    ),
+];
+
+const SIMULATIONS: [(u16, Days); 1] = [(2022, &Y2022)];
+
+// `None` if the day has no simulation to step through, or was compiled out
+pub fn simulation(id: DayId, inp: &str) -> Option<ParseResult<Box<dyn Simulation>>> {
+    let (_, days) = SIMULATIONS.iter().find(|&&(year, _)| year == id.year)?;
+    let &(_, start) = days.iter().find(|&&(day, _)| day == id.day)?;
+    Some(start?(inp))
+}
+
+// Every state seen so far, so that stepping back is only a lookup
+pub struct Session {
+    history: Vec<Box<dyn Simulation>>,
+    at: usize,
+    finished: bool,
+}
+
+impl Session {
+    pub fn new(simulation: Box<dyn Simulation>) -> Self {
+        Self {
+            history: vec![simulation],
+            at: 0,
+            finished: false,
+        }
+    }
+
+    pub fn step(&mut self) -> bool {
+        if self.at + 1 < self.history.len() {
+            self.at += 1;
+            return true;
+        }
+        if self.finished {
+            return false;
+        }
+        let mut next = self.history[self.at].snapshot();
+        if next.step() {
+            self.history.push(next);
+            self.at += 1;
+            true
+        } else {
+            self.finished = true;
+            false
+        }
+    }
+
+    pub fn back(&mut self, steps: usize) {
+        self.at = self.at.saturating_sub(steps);
+    }
+
+    // Index of the current state among the stored ones
+    pub fn at(&self) -> usize {
+        self.at
+    }
+
+    pub fn frame(&self) -> Frame {
+        self.history[self.at].frame()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Step(usize),
+    Back(usize),
+    // Play or pause
+    Toggle,
+    Quit,
+}
+
+// Digits typed before `n` or `b` repeat it, `12n` steps twelve times
+#[derive(Debug, Default)]
+pub struct Keys {
+    count: Option<usize>,
+}
+
+impl Keys {
+    pub fn push(&mut self, key: u8) -> Option<Command> {
+        if key.is_ascii_digit() {
+            let digit = (key - b'0') as usize;
+            self.count = Some(self.count.unwrap_or(0).saturating_mul(10) + digit);
+            return None;
+        }
+        let count = self.count.take().unwrap_or(1);
+        match key {
+            b'n' | b'\n' => Some(Command::Step(count)),
+            b'b' => Some(Command::Back(count)),
+            b' ' | b'p' => Some(Command::Toggle),
+            // Ctrl-C comes as a key, the terminal does not send signals
+            b'q' | 3 => Some(Command::Quit),
+            _ => None,
+        }
+    }
+}
+
+fn draw(session: &Session, keys: &Keys, playing: bool, out: &mut impl Write) -> io::Result<()> {
+    Ansi::new(&mut *out, Duration::ZERO).frame(&session.frame())?;
+    let state = if playing { "playing" } else { "paused" };
+    let count = keys.count.map_or(String::new(), |n| format!(" {n}"));
+    writeln!(
+        out,
+        "Snapshot {}, {state}{count}\n[n]ext [b]ack [space] play/pause [q]uit, a number first repeats n and b",
+        session.at()
+    )?;
+    out.flush()
+}
+
+// Redraws the session after every key. While playing it steps every `delay`
+// until a key comes or the simulation ends. Returns once `q` is pressed or the
+// keys run out.
+pub fn drive(
+    session: &mut Session,
+    keys: Receiver<u8>,
+    mut out: impl Write,
+    delay: Duration,
+) -> io::Result<()> {
+    let mut parser = Keys::default();
+    let mut playing = false;
+    loop {
+        draw(session, &parser, playing, &mut out)?;
+        let key = if playing {
+            match keys.recv_timeout(delay) {
+                Ok(key) => Some(key),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+        } else {
+            match keys.recv() {
+                Ok(key) => Some(key),
+                Err(_) => return Ok(()),
+            }
+        };
+        let Some(key) = key else {
+            playing = session.step();
+            continue;
+        };
+        match parser.push(key) {
+            Some(Command::Step(n)) => {
+                playing = false;
+                for _ in 0..n {
+                    if !session.step() {
+                        break;
+                    }
+                }
+            }
+            Some(Command::Back(n)) => {
+                playing = false;
+                session.back(n);
+            }
+            Some(Command::Toggle) => playing = !playing,
+            Some(Command::Quit) => return Ok(()),
+            None => {}
+        }
+    }
+}
+
+fn stty(args: &[&str]) -> Result<String, String> {
+    let tty = File::open("/dev/tty").map_err(|e| format!("No terminal: {e}"))?;
+    let output = Shell::new("stty")
+        .args(args)
+        .stdin(tty)
+        .output()
+        .map_err(|e| format!("Cannot run stty: {e}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+// The saved terminal settings, put back however the stepping ends
+struct Terminal(String);
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = stty(&[&self.0]);
+    }
+}
+
+// Steps through the simulation of a day in the terminal. Keys are read from
+// the terminal itself, so the input may come from stdin.
+pub fn interactive(id: DayId, inp: &str, delay: Duration) -> Result<(), String> {
+    runner::available(id)?;
+    let simulation = simulation(id, inp)
+        .ok_or_else(|| format!("{id} has no simulation to step through"))?
+        .map_err(|e| e.locate(inp).in_day(id.day).to_string())?;
+    let tty = File::open("/dev/tty").map_err(|e| format!("No terminal: {e}"))?;
+    // Keys without waiting for Enter, without echoing them, and Ctrl-C as a
+    // key so that the settings are restored
+    let _terminal = Terminal(stty(&["-g"])?);
+    stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+    let (send, keys) = mpsc::channel();
+    thread::spawn(move || {
+        for key in BufReader::new(tty).bytes() {
+            if key.map_or(true, |key| send.send(key).is_err()) {
+                break;
+            }
+        }
+    });
+    drive(&mut Session::new(simulation), keys, io::stdout(), delay).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{drive, simulation, Command, Keys, Session};
+    use crate::runner::{available, read_input, DayId, DEFAULT_YEAR};
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    // `None` if the day is compiled out
+    fn session(day: u8) -> Option<Session> {
+        let id = DayId::new(DEFAULT_YEAR, day);
+        available(id).ok()?;
+        let inp = read_input(id, "example");
+        Some(Session::new(simulation(id, &inp).unwrap().unwrap()))
+    }
+
+    #[test]
+    fn parses_keys() {
+        let mut keys = Keys::default();
+        let commands: Vec<_> = b"12nbx3b q\x03"
+            .iter()
+            .filter_map(|&k| keys.push(k))
+            .collect();
+        assert_eq!(
+            commands,
+            [
+                Command::Step(12),
+                Command::Back(1),
+                Command::Back(3),
+                Command::Toggle,
+                Command::Quit,
+                Command::Quit
+            ]
+        );
+    }
+
+    #[test]
+    fn steps_back_through_snapshots() {
+        let Some(mut session) = session(23) else {
+            return;
+        };
+        let first = session.frame();
+        let mut steps = 0;
+        while session.step() {
+            steps += 1;
+        }
+        // The first round in which no elf moves is the last one
+        assert_eq!(steps, 20);
+        assert_eq!(session.frame().step, 20);
+        let last = session.frame();
+        session.back(5);
+        assert_eq!(session.frame().step, 15);
+        session.back(100);
+        assert_eq!(session.frame(), first);
+        for _ in 0..20 {
+            assert!(session.step());
+        }
+        assert_eq!(session.frame(), last);
+        assert!(!session.step());
+    }
+
+    #[test]
+    fn walks_the_three_trips() {
+        let Some(mut session) = session(24) else {
+            return;
+        };
+        while session.step() {}
+        // The minute the expedition is back at the exit with the snacks
+        assert_eq!(session.frame().step, 54);
+        assert!(session.frame().cells.iter().any(|&c| c == 'E'));
+    }
+
+    #[test]
+    fn drives_with_keys() {
+        let Some(mut session) = session(23) else {
+            return;
+        };
+        let (send, keys) = mpsc::channel();
+        for &key in b"5n2bn" {
+            send.send(key).unwrap();
+        }
+        drop(send);
+        let mut out = vec![];
+        drive(&mut session, keys, &mut out, Duration::ZERO).unwrap();
+        assert_eq!(session.frame().step, 4);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.ends_with("Snapshot 4, paused\n[n]ext [b]ack [space] play/pause [q]uit, a number first repeats n and b\n"));
+    }
+}
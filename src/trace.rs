@@ -0,0 +1,296 @@
+use crate::runner::{DayId, Format};
+use crate::solution::Part;
+use json::{object, JsonValue};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::rc::Rc;
+use std::str::FromStr;
+
+// How much a solver reports while it runs, each level includes the ones before it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    // Progress, like the size of a search frontier after every step
+    Info,
+    // Intermediate results, possibly large
+    Debug,
+}
+
+impl FromStr for Level {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(Self::Info),
+            "debug" => Ok(Self::Debug),
+            _ => Err(format!("Unknown trace level {s}")),
+        }
+    }
+}
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Info => f.pad("info"),
+            Self::Debug => f.pad("debug"),
+        }
+    }
+}
+
+// Something that happened inside a solver, with named values
+#[derive(Clone, Debug, PartialEq)]
+pub struct Event {
+    pub level: Level,
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, JsonValue)>,
+}
+
+impl Event {
+    pub fn field(&self, key: &str) -> Option<&JsonValue> {
+        self.fields.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        let mut value = object! {
+            level: self.level.to_string(),
+            event: self.name,
+        };
+        for (key, field) in &self.fields {
+            value[*key] = field.clone();
+        }
+        value
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        for (key, value) in &self.fields {
+            write!(f, " {key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+pub trait Observer {
+    fn event(&mut self, event: &Event);
+}
+
+// Writes the events of one run to stderr, so that they do not mix with answers
+pub struct Log {
+    id: DayId,
+    part: Part,
+    format: Format,
+}
+
+impl Log {
+    pub fn new(id: DayId, part: Part, format: Format) -> Self {
+        Self { id, part, format }
+    }
+}
+
+impl Observer for Log {
+    fn event(&mut self, event: &Event) {
+        match self.format {
+            Format::Text => eprintln!("[{} part {}] {event}", self.id, self.part),
+            Format::Json => {
+                let mut value = event.to_json();
+                value["year"] = self.id.year.into();
+                value["day"] = self.id.day.into();
+                value["part"] = u8::from(self.part).into();
+                eprintln!("{}", value.dump());
+            }
+        }
+    }
+}
+
+// Keeps every event, clones share them
+#[derive(Clone, Debug, Default)]
+pub struct Recorder {
+    events: Rc<RefCell<Vec<Event>>>,
+}
+
+impl Recorder {
+    pub fn events(&self) -> Vec<Event> {
+        self.events.borrow().clone()
+    }
+}
+
+impl Observer for Recorder {
+    fn event(&mut self, event: &Event) {
+        self.events.borrow_mut().push(event.clone());
+    }
+}
+
+type Current = Option<(Level, Box<dyn Observer>)>;
+
+thread_local! {
+    static CURRENT: RefCell<Current> = const { RefCell::new(None) };
+}
+
+// Puts back the previous observer, even if the solver panics
+struct Restore(Current);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+    }
+}
+
+// Runs `f` with the events up to `level` going to `observer`. Observers are
+// per thread, events of other threads are not seen.
+pub fn observe<T>(level: Level, observer: Box<dyn Observer>, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.with(|current| current.borrow_mut().replace((level, observer)));
+    let _restore = Restore(previous);
+    f()
+}
+
+pub fn enabled(level: Level) -> bool {
+    CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(|&(max, _)| level <= max)
+    })
+}
+
+pub fn emit(event: Event) {
+    CURRENT.with(|current| {
+        if let Some((max, observer)) = current.borrow_mut().as_mut() {
+            if event.level <= *max {
+                observer.event(&event);
+            }
+        }
+    });
+}
+
+// `event!(Info, "step", step = i, states = states.len())`. The fields are
+// only evaluated if someone listens at that level. Unused when the days that
+// report events are compiled out.
+#[allow(unused_macros)]
+macro_rules! event {
+    ($level:ident, $name:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::emit($crate::trace::Event {
+                level: $crate::trace::Level::$level,
+                name: $name,
+                fields: vec![$((stringify!($key), $value.into())),*],
+            });
+        }
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use event;
+
+// Trace level of every day. Parsed from a comma separated list of `<level>`
+// for all days and `<day>=<level>` or `<day>=off` for single ones.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Filter {
+    all: Option<Level>,
+    days: HashMap<u8, Option<Level>>,
+}
+
+impl Filter {
+    pub fn level(&self, day: u8) -> Option<Level> {
+        self.days.get(&day).copied().unwrap_or(self.all)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Self::default();
+        for item in s.split(',') {
+            match item.split_once('=') {
+                Some((day, level)) => {
+                    let day = day.parse().map_err(|_| format!("Unknown day {day}"))?;
+                    let level = match level {
+                        "off" => None,
+                        level => Some(level.parse()?),
+                    };
+                    filter.days.insert(day, level);
+                }
+                None => filter.all = Some(item.parse()?),
+            }
+        }
+        Ok(filter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{emit, enabled, observe, Event, Filter, Level, Recorder};
+    use crate::runner::{self, DayId, DEFAULT_YEAR};
+    use crate::solution::Part;
+    use std::cell::Cell;
+    use std::panic;
+
+    #[test]
+    fn parses_filters() {
+        let filter: Filter = "19=debug,info,7=off".parse().unwrap();
+        assert_eq!(filter.level(19), Some(Level::Debug));
+        assert_eq!(filter.level(7), None);
+        assert_eq!(filter.level(1), Some(Level::Info));
+        assert_eq!(Filter::default().level(1), None);
+        assert!("19=loud".parse::<Filter>().is_err());
+        assert!("x=info".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn observes_up_to_level() {
+        let recorder = Recorder::default();
+        let evaluated = Cell::new(false);
+        assert!(!enabled(Level::Info));
+        observe(Level::Info, Box::new(recorder.clone()), || {
+            assert!(enabled(Level::Info) && !enabled(Level::Debug));
+            event!(Info, "step", step = 3, states = 12usize);
+            event!(Debug, "state", state = evaluated.replace(true));
+            let inner = Recorder::default();
+            observe(Level::Debug, Box::new(inner.clone()), || {
+                event!(Debug, "nested");
+            });
+            assert_eq!(inner.events().len(), 1);
+        });
+        assert!(!enabled(Level::Info) && !evaluated.get());
+        // Nobody listens any more
+        emit(Event {
+            level: Level::Info,
+            name: "lost",
+            fields: vec![],
+        });
+
+        let events = recorder.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].to_string(), "step step=3 states=12");
+        assert_eq!(
+            events[0].to_json().dump(),
+            r#"{"level":"info","event":"step","step":3,"states":12}"#
+        );
+    }
+
+    #[test]
+    fn restores_after_panic() {
+        let result = panic::catch_unwind(|| {
+            observe(Level::Debug, Box::new(Recorder::default()), || panic!())
+        });
+        assert!(result.is_err());
+        assert!(!enabled(Level::Info));
+    }
+
+    #[test]
+    fn solvers_report_events() {
+        let id = DayId::new(DEFAULT_YEAR, 7);
+        if runner::available(id).is_err() {
+            return;
+        }
+        let inp = runner::read_input(id, "example");
+        let recorder = Recorder::default();
+        let report = observe(Level::Debug, Box::new(recorder.clone()), || {
+            runner::run(id, Part::One, &inp)
+        });
+        assert_eq!(report.answer.unwrap().to_string(), "95437");
+        let events = recorder.events();
+        assert_eq!(events.len(), 4);
+        assert!(events
+            .iter()
+            .any(|e| e.field("path").and_then(|p| p.as_str()) == Some("/a/e")));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::answers::Answers;
+use crate::runner::{self, DayId};
+use crate::solution::Part;
+use std::panic::{self, AssertUnwindSafe};
+
+pub const TARGETS: [&str; 2] = ["example", "main"];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+// Line by line comparison, enough for numbers, strings and pictures
+fn diff(expected: &str, actual: &str) -> String {
+    let (expected, actual): (Vec<_>, Vec<_>) =
+        (expected.lines().collect(), actual.lines().collect());
+    let mut out = vec![];
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => out.push(format!("      {e}")),
+            (e, a) => {
+                if let Some(e) = e {
+                    out.push(format!("    - {e}"));
+                }
+                if let Some(a) = a {
+                    out.push(format!("    + {a}"));
+                }
+            }
+        }
+    }
+    out.join("\n")
+}
+
+pub fn check(id: DayId, part: Part, target: &str, answers: &Answers) -> (Status, String) {
+    let inp = runner::read_input(id, target);
+    let actual = match panic::catch_unwind(AssertUnwindSafe(|| runner::run(id, part, &inp))) {
+        Ok(report) => match report.answer {
+            Ok(answer) => answer.to_string(),
+            Err(e) => return (Status::Fail(e.to_string()), String::new()),
+        },
+        Err(_) => return (Status::Fail("solver panicked".to_string()), String::new()),
+    };
+    let status = match answers.get(target, part) {
+        None => Status::Missing,
+        Some(expected) if expected == actual => Status::Pass,
+        Some(expected) => Status::Fail(diff(expected, &actual)),
+    };
+    (status, actual)
+}
+
+// Prints one line per day, part and target, returns whether all passed.
+// With `record` the missing answers are stored as the expected ones. Days
+// that were compiled out are skipped.
+pub fn verify(days: &[DayId], targets: &[&str], record: bool) -> bool {
+    let (mut passed, mut failed, mut missing, mut skipped) = (0, 0, 0, 0);
+    for &id in days {
+        if let Err(e) = runner::available(id) {
+            skipped += 1;
+            println!("{e}");
+            continue;
+        }
+        let mut answers = Answers::load(id).expect("Broken answers file");
+        let mut recorded = false;
+        for target in targets {
+            for part in [Part::One, Part::Two] {
+                let (status, actual) = check(id, part, target, &answers);
+                let name = format!("{id} part {part} {target:<7}");
+                match status {
+                    Status::Pass => {
+                        passed += 1;
+                        println!("{name} PASS");
+                    }
+                    Status::Fail(diff) => {
+                        failed += 1;
+                        println!("{name} FAIL\n{diff}");
+                    }
+                    Status::Missing if record => {
+                        println!("{name} RECORDED {actual:?}");
+                        answers.set(target, part, actual);
+                        recorded = true;
+                    }
+                    Status::Missing => {
+                        missing += 1;
+                        println!("{name} MISSING (got {actual:?})");
+                    }
+                }
+            }
+        }
+        if recorded {
+            answers.save(id).expect("Failed to save answers");
+        }
+    }
+    let skipped = match skipped {
+        0 => String::new(),
+        1 => ", 1 day compiled out".to_string(),
+        n => format!(", {n} days compiled out"),
+    };
+    println!("{passed} passed, {failed} failed, {missing} missing{skipped}");
+    failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff;
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(diff("#.\n.#", "#.\n##"), "      #.\n    - .#\n    + ##");
+        assert_eq!(diff("1", "1\n2"), "      1\n    + 2");
+    }
+}
@@ -0,0 +1,81 @@
+use crate::runner::Runner;
+use crate::solution;
+
+#[cfg(feature = "y2022-day1")]
+pub mod day1;
+#[cfg(feature = "y2022-day10")]
+pub mod day10;
+#[cfg(feature = "y2022-day11")]
+pub mod day11;
+#[cfg(feature = "y2022-day12")]
+pub mod day12;
+#[cfg(feature = "y2022-day13")]
+pub mod day13;
+#[cfg(feature = "y2022-day14")]
+pub mod day14;
+#[cfg(feature = "y2022-day15")]
+pub mod day15;
+#[cfg(feature = "y2022-day16")]
+pub mod day16;
+#[cfg(feature = "y2022-day17")]
+pub mod day17;
+#[cfg(feature = "y2022-day18")]
+pub mod day18;
+#[cfg(feature = "y2022-day19")]
+pub mod day19;
+#[cfg(feature = "y2022-day2")]
+pub mod day2;
+#[cfg(feature = "y2022-day20")]
+pub mod day20;
+#[cfg(feature = "y2022-day21")]
+pub mod day21;
+#[cfg(feature = "y2022-day22")]
+pub mod day22;
+#[cfg(feature = "y2022-day23")]
+pub mod day23;
+#[cfg(feature = "y2022-day24")]
+pub mod day24;
+#[cfg(feature = "y2022-day25")]
+pub mod day25;
+#[cfg(feature = "y2022-day3")]
+pub mod day3;
+#[cfg(feature = "y2022-day4")]
+pub mod day4;
+#[cfg(feature = "y2022-day5")]
+pub mod day5;
+#[cfg(feature = "y2022-day6")]
+pub mod day6;
+#[cfg(feature = "y2022-day7")]
+pub mod day7;
+#[cfg(feature = "y2022-day8")]
+pub mod day8;
+#[cfg(feature = "y2022-day9")]
+pub mod day9;
+
+pub const DAYS: [Option<Runner>; 25] = [
+    solution::enabled!("y2022-day1", solution::run::<day1::Solver>),
+    solution::enabled!("y2022-day2", solution::run::<day2::Solver>),
+    solution::enabled!("y2022-day3", solution::run::<day3::Solver>),
+    solution::enabled!("y2022-day4", solution::run::<day4::Solver>),
+    solution::enabled!("y2022-day5", solution::run::<day5::Solver>),
+    solution::enabled!("y2022-day6", solution::run::<day6::Solver>),
+    solution::enabled!("y2022-day7", solution::run::<day7::Solver>),
+    solution::enabled!("y2022-day8", solution::run::<day8::Solver>),
+    solution::enabled!("y2022-day9", solution::run::<day9::Solver>),
+    solution::enabled!("y2022-day10", solution::run::<day10::Solver>),
+    solution::enabled!("y2022-day11", solution::run::<day11::Solver>),
+    solution::enabled!("y2022-day12", solution::run::<day12::Solver>),
+    solution::enabled!("y2022-day13", solution::run::<day13::Solver>),
+    solution::enabled!("y2022-day14", solution::run::<day14::Solver>),
+    solution::enabled!("y2022-day15", solution::run::<day15::Solver>),
+    solution::enabled!("y2022-day16", solution::run::<day16::Solver>),
+    solution::enabled!("y2022-day17", solution::run::<day17::Solver>),
+    solution::enabled!("y2022-day18", solution::run::<day18::Solver>),
+    solution::enabled!("y2022-day19", solution::run::<day19::Solver>),
+    solution::enabled!("y2022-day20", solution::run::<day20::Solver>),
+    solution::enabled!("y2022-day21", solution::run::<day21::Solver>),
+    solution::enabled!("y2022-day22", solution::run::<day22::Solver>),
+    solution::enabled!("y2022-day23", solution::run::<day23::Solver>),
+    solution::enabled!("y2022-day24", solution::run::<day24::Solver>),
+    solution::enabled!("y2022-day25", solution::run::<day25::Solver>),
+];
@@ -0,0 +1,74 @@
+use crate::error::{field, ParseResult};
+use crate::gen::Rng;
+use std::cmp::max;
+
+fn process_spaced(inp: &str, processor: &mut dyn FnMut(i32)) -> ParseResult<()> {
+    for elf in inp.trim().split("\n\n") {
+        processor(
+            elf.lines()
+                .map(|row| field::<i32>(row, "calories"))
+                .sum::<ParseResult<_>>()?,
+        );
+    }
+    Ok(())
+}
+
+pub fn parse(inp: &str) -> ParseResult<Vec<i32>> {
+    let mut totals = vec![];
+    process_spaced(inp, &mut |x| totals.push(x))?;
+    Ok(totals)
+}
+
+pub fn part1(totals: &[i32]) -> i32 {
+    let mut max_: i32 = 0;
+    totals.iter().for_each(|&x| max_ = max(max_, x));
+    max_
+}
+
+pub fn part2(totals: &[i32]) -> i32 {
+    let mut best3: [i32; 3] = [0; 3];
+    totals.iter().for_each(|&x| {
+        best3.sort();
+        if best3[0] < x {
+            best3[0] = x;
+        }
+    });
+    best3.iter().sum()
+}
+
+// `size` elves carrying up to ten snacks each
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let elves: Vec<String> = (0..size.max(3))
+        .map(|_| {
+            let snacks: Vec<String> = (0..rng.range(1..=10))
+                .map(|_| rng.range(1000..=60000).to_string())
+                .collect();
+            snacks.join("\n")
+        })
+        .collect();
+    elves.join("\n\n") + "\n"
+}
+
+crate::solution::solution!(Vec<i32>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task1/example.txt").unwrap()).unwrap()),
+            24000,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task1/example.txt").unwrap()).unwrap()),
+            45000,
+        );
+    }
+}
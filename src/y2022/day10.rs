@@ -0,0 +1,130 @@
+use crate::error::{field, ParseError, ParseResult};
+use crate::gen::Rng;
+
+#[derive(Debug)]
+pub enum Command {
+    Addx(i32),
+    Nop,
+}
+impl Command {
+    fn parse(line: &str) -> ParseResult<Command> {
+        match line.split_once(' ') {
+            None if line == "noop" => Ok(Command::Nop),
+            Some(("addx", value)) => Ok(Command::Addx(field(value, "addx value")?)),
+            _ => Err(ParseError::new(line, "Unknown command")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Cycle {
+    cycle: i32,
+    x: i32,
+}
+impl Cycle {
+    fn next(&mut self, inc: i32) {
+        self.cycle += 1;
+        self.x += inc;
+    }
+}
+
+const TARGETS: [i32; 6] = [20, 60, 100, 140, 180, 220];
+
+pub fn parse(inp: &str) -> ParseResult<Vec<Command>> {
+    inp.lines().map(Command::parse).collect()
+}
+
+fn solve(commands: &[Command], tick: &mut dyn FnMut(&mut Cycle)) {
+    let mut cycle = Cycle { cycle: 1, x: 1 };
+    for command in commands {
+        tick(&mut cycle);
+        cycle.next(0);
+
+        match *command {
+            Command::Nop => {}
+            Command::Addx(inc) => {
+                tick(&mut cycle);
+                cycle.next(inc);
+            }
+        }
+    }
+}
+
+pub fn part1(commands: &[Command]) -> i32 {
+    let mut total = 0i32;
+    let mut tick = |cycle: &mut Cycle| {
+        if TARGETS.contains(&cycle.cycle) {
+            total += cycle.cycle * cycle.x;
+        }
+    };
+    solve(commands, &mut tick);
+    total
+}
+
+pub fn part2(commands: &[Command]) -> Vec<String> {
+    let mut screen = vec![String::new()];
+    let mut tick = |cycle: &mut Cycle| {
+        let row = screen.last_mut().unwrap();
+        if (cycle.x - cycle.cycle % 40 + 1).abs() <= 1 {
+            row.push('#')
+        } else {
+            row.push('.')
+        }
+
+        if cycle.cycle % 40 == 0 {
+            screen.push(String::new());
+        }
+    };
+    solve(commands, &mut tick);
+    screen.retain(|row| !row.is_empty());
+    screen
+}
+
+// Runs for at least `size` cycles and the whole screen, keeping the sprite on it
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (mut out, mut cycles, mut x) = (String::new(), 0, 1);
+    while cycles < size.max(240) {
+        if rng.chance(0.3) {
+            out += "noop\n";
+            cycles += 1;
+        } else {
+            let next = rng.range(0..=39);
+            let inc = (next - x).clamp(-15, 15);
+            out += &format!("addx {inc}\n");
+            x += inc;
+            cycles += 2;
+        }
+    }
+    out
+}
+
+crate::solution::solution!(Vec<Command>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task10/example.txt").unwrap()).unwrap()),
+            13140,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task10/example.txt").unwrap()).unwrap()),
+            [
+                "##..##..##..##..##..##..##..##..##..##..",
+                "###...###...###...###...###...###...###.",
+                "####....####....####....####....####....",
+                "#####.....#####.....#####.....#####.....",
+                "######......######......######......###.",
+                "#######.......#######.......#######.....",
+            ],
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use crate::cycle::CycleFinder;
+use crate::error::{field, ParseError, ParseResult};
+use crate::gen::Rng;
+use sscanf::sscanf;
+use std::vec::Vec;
+
+#[derive(Clone, Debug)]
+struct Test {
+    divisor: u64,
+    target_true: usize,
+    target_false: usize,
+}
+impl Test {
+    fn get_target(&self, worry: &u64) -> usize {
+        if worry.is_multiple_of(self.divisor) {
+            self.target_true
+        } else {
+            self.target_false
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Op {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+impl Op {
+    fn new(op: &str, right: &str) -> ParseResult<Op> {
+        match (op, right) {
+            ("*", "old") => Ok(Self::Square),
+            ("*", r) => Ok(Self::Mul(field(r, "operand")?)),
+            ("+", r) => Ok(Self::Add(field(r, "operand")?)),
+            _ => Err(ParseError::new(op, "Unknown operation")),
+        }
+    }
+    // `None` on overflow
+    fn exec(&self, left: &u64) -> Option<u64> {
+        match self {
+            Self::Square => left.checked_mul(*left),
+            Self::Mul(r) => left.checked_mul(*r),
+            Self::Add(r) => left.checked_add(*r),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    hand: Vec<u64>,
+    op: Op,
+    test: Test,
+}
+impl Monkey {
+    fn parse(block: &str) -> ParseResult<Monkey> {
+        let (_, items, op, right, divisor, target_true, target_false) = sscanf!(
+            block,
+            "Monkey {usize}:
+  Starting items: {str}
+  Operation: new = old {str} {str}
+  Test: divisible by {u64}
+    If true: throw to monkey {usize}
+    If false: throw to monkey {usize}"
+        )
+        .map_err(|_| ParseError::new(block, "Invalid monkey description"))?;
+        Ok(Monkey {
+            hand: items
+                .split(", ")
+                .map(|x| field(x, "worry level"))
+                .collect::<ParseResult<_>>()?,
+            op: Op::new(op, right)?,
+            test: Test {
+                divisor,
+                target_true,
+                target_false,
+            },
+        })
+    }
+}
+
+pub fn parse(inp: &str) -> ParseResult<Vec<Monkey>> {
+    let blocks: Vec<_> = inp.trim().split("\n\n").collect();
+    let monkeys: Vec<_> = blocks
+        .iter()
+        .map(|block| Monkey::parse(block))
+        .collect::<ParseResult<_>>()?;
+    for (block, monkey) in blocks.iter().zip(&monkeys) {
+        if monkey.test.target_true.max(monkey.test.target_false) >= monkeys.len() {
+            return Err(ParseError::new(block, "Throw target out of range"));
+        }
+    }
+    Ok(monkeys)
+}
+
+// Items never interact, so each one is followed on its own until its
+// (monkey, worry) state at the start of a round comes back. Worry levels are
+// only reduced by `modulo` when they are not divided, as the division does not
+// commute with it.
+fn item_inspections(
+    monkeys: &[Monkey],
+    mut at: usize,
+    mut worry: u64,
+    rounds: usize,
+    relax: u64,
+    modulo: u64,
+) -> Option<Vec<u64>> {
+    let mut inspects = vec![0u64; monkeys.len()];
+    let mut finder = CycleFinder::new();
+    for _ in 0..rounds {
+        if let Some(cycle) = finder.push((at, worry), inspects.clone()) {
+            let history = finder.history();
+            let (recorded, periods) = cycle.reduce(rounds);
+            let (first, second) = (&history[cycle.start], &history[cycle.start + cycle.period]);
+            return Some(
+                (0..monkeys.len())
+                    .map(|i| history[recorded][i] + (second[i] - first[i]) * periods as u64)
+                    .collect(),
+            );
+        }
+        // Monkeys go in order, an item thrown forward is inspected again this round
+        loop {
+            let m = &monkeys[at];
+            inspects[at] += 1;
+            worry = m.op.exec(&worry)?;
+            worry = if relax == 1 {
+                worry % modulo
+            } else {
+                worry / relax
+            };
+            let next = m.test.get_target(&worry);
+            let forward = next > at;
+            at = next;
+            if !forward {
+                break;
+            }
+        }
+    }
+    Some(inspects)
+}
+
+// `None` if the worry levels overflow
+pub fn monkey_business(monkeys: &[Monkey], rounds: usize, relax: u64) -> Option<u64> {
+    let modulo =
+        monkeys
+            .iter()
+            .map(|x| x.test.divisor)
+            .fold(1, |acc, x| if acc % x == 0 { acc } else { acc * x });
+    let mut inspects = vec![0; monkeys.len()];
+    for (at, m) in monkeys.iter().enumerate() {
+        for &worry in &m.hand {
+            let item = item_inspections(monkeys, at, worry, rounds, relax, modulo)?;
+            inspects
+                .iter_mut()
+                .zip(item)
+                .for_each(|(total, n)| *total += n);
+        }
+    }
+    inspects.sort();
+    Some(inspects.iter().rev().take(2).product::<u64>())
+}
+
+pub fn part1(monkeys: &[Monkey]) -> u64 {
+    monkey_business(monkeys, 20, 3).expect("Worry levels should fit")
+}
+
+pub fn part2(monkeys: &[Monkey]) -> u64 {
+    monkey_business(monkeys, 10_000, 1).expect("Worry levels should fit")
+}
+
+// Every monkey tests for a different prime and throws to two other monkeys.
+// Squares blow up quickly, so inputs are drawn until the worry levels fit.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    loop {
+        let inp = monkeys(rng, size);
+        if monkey_business(&parse(&inp).unwrap(), 20, 3).is_some() {
+            return inp;
+        }
+    }
+}
+
+fn monkeys(rng: &mut Rng, size: usize) -> String {
+    let n = size.clamp(3, 8);
+    let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let monkeys: Vec<String> = (0..n)
+        .map(|i| {
+            let items: Vec<String> = (0..rng.range(1..=6))
+                .map(|_| rng.range(50..=99).to_string())
+                .collect();
+            let op = match rng.below(3) {
+                0 => "* old".to_string(),
+                1 => format!("* {}", rng.range(2..=19)),
+                _ => format!("+ {}", rng.range(1..=8)),
+            };
+            let target_true = (i + rng.range(1..=n - 1)) % n;
+            let target_false = loop {
+                let target = (i + rng.range(1..=n - 1)) % n;
+                if target != target_true {
+                    break target;
+                }
+            };
+            format!(
+                "Monkey {i}:\n  Starting items: {}\n  Operation: new = old {op}\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {target_true}\n    \
+                 If false: throw to monkey {target_false}\n",
+                items.join(", "),
+                primes[i],
+            )
+        })
+        .collect();
+    monkeys.join("\n")
+}
+
+crate::solution::solution!(Vec<Monkey>);
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, monkey_business, parse, part1, part2, Monkey, Op};
+    use crate::gen::Rng;
+    use std::fs;
+
+    // Round by round and monkey by monkey, with the worry levels reduced by
+    // `modulo` if any. `None` once they overflow.
+    fn naive(monkeys: &[Monkey], rounds: usize, relax: u128, modulo: Option<u128>) -> Option<u64> {
+        let mut hands: Vec<Vec<u128>> = monkeys
+            .iter()
+            .map(|m| m.hand.iter().map(|&w| w.into()).collect())
+            .collect();
+        let mut inspects = vec![0; monkeys.len()];
+        for _ in 0..rounds {
+            for (i, m) in monkeys.iter().enumerate() {
+                for worry in std::mem::take(&mut hands[i]) {
+                    inspects[i] += 1;
+                    let worry = match m.op {
+                        Op::Add(r) => worry.checked_add(r.into()),
+                        Op::Mul(r) => worry.checked_mul(r.into()),
+                        Op::Square => worry.checked_mul(worry),
+                    }?;
+                    let worry = modulo.map_or(worry, |modulo| worry % modulo) / relax;
+                    let target = if worry.is_multiple_of(m.test.divisor.into()) {
+                        m.test.target_true
+                    } else {
+                        m.test.target_false
+                    };
+                    hands[target].push(worry);
+                }
+            }
+        }
+        inspects.sort();
+        Some(inspects.iter().rev().take(2).product())
+    }
+
+    #[test]
+    fn matches_naive_on_random_inputs() {
+        let mut rng = Rng::new(11);
+        for _ in 0..20 {
+            let size = rng.range(3..=8);
+            let monkeys = parse(&generate(&mut rng, size)).unwrap();
+            assert_eq!(
+                monkey_business(&monkeys, 20, 3),
+                naive(&monkeys, 20, 3, None)
+            );
+            // Exact worry levels as long as they fit
+            for rounds in 1..=100 {
+                let Some(expected) = naive(&monkeys, rounds, 1, None) else {
+                    break;
+                };
+                assert_eq!(
+                    monkey_business(&monkeys, rounds, 1),
+                    Some(expected),
+                    "{rounds} rounds"
+                );
+            }
+            let modulo = monkeys.iter().map(|m| u128::from(m.test.divisor)).product();
+            assert_eq!(
+                Some(part2(&monkeys)),
+                naive(&monkeys, 10_000, 1, Some(modulo))
+            );
+        }
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task11/example.txt").unwrap()).unwrap()),
+            10605,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task11/example.txt").unwrap()).unwrap()),
+            2713310158,
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::error::{ParseError, ParseResult};
+use crate::gen::Rng;
+use crate::grid::{Grid, Pos};
+use crate::search::{self, Path};
+
+#[derive(Clone, Debug)]
+pub struct HillMap {
+    elevations: Grid<u8>,
+    start: Pos,
+    end: Pos,
+}
+impl HillMap {
+    fn from_string(inp: &str) -> ParseResult<Self> {
+        let map = Grid::parse(inp, |c| matches!(c, 'S' | 'E' | 'a'..='z').then_some(c))?;
+        let start = map.position(|&c| c == 'S');
+        let end = map.position(|&c| c == 'E');
+        Ok(HillMap {
+            elevations: map.map(|&c| match c {
+                'S' => 0,
+                'E' => b'z' - b'a',
+                c => c as u8 - b'a',
+            }),
+            start: start.ok_or_else(|| ParseError::new(inp, "No start position"))?,
+            end: end.ok_or_else(|| ParseError::new(inp, "No end position"))?,
+        })
+    }
+    fn neighbours(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let current_elev = self.elevations[p];
+        self.elevations
+            .neighbours4(p)
+            // Reverse direction (moving from z to a)
+            .filter(move |&n| current_elev <= self.elevations[n] + 1)
+    }
+}
+
+// Searches from the end, so that part 2 can look for the closest lowest point
+fn solve(map: &HillMap, is_end: impl FnMut(&Pos) -> bool) -> Option<Path<Pos, usize>> {
+    search::bfs(map.end, |&p| map.neighbours(p), is_end)
+}
+
+pub fn parse(inp: &str) -> ParseResult<HillMap> {
+    HillMap::from_string(inp)
+}
+
+pub fn part1(map: &HillMap) -> usize {
+    solve(map, |&at| map.start == at)
+        .expect("Should be solvable!")
+        .cost
+}
+pub fn part2(map: &HillMap) -> usize {
+    solve(map, |&at| map.elevations[at] == 0)
+        .expect("Should be solvable!")
+        .cost
+}
+
+// The hill rises evenly from S in the top left corner to E in the bottom
+// right one. A random staircase between them keeps that rise, so E is
+// always reachable.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (h, w) = (size.max(13), (2 * size).max(14));
+    let base = |(r, c): Pos| ((r + c) * 25 / (h + w - 2)) as u8;
+    let cells = (0..h * w)
+        .map(|i| {
+            let top = (base((i / w, i % w)) + 2).min(25);
+            (b'a' + rng.range(0..=top)) as char
+        })
+        .collect();
+    let mut map = Grid::from_vec(cells, w);
+    let (start, end) = ((0, 0), (h - 1, w - 1));
+    let mut at = start;
+    while at != end {
+        map[at] = (b'a' + base(at)) as char;
+        let down = at.1 == w - 1 || (at.0 < h - 1 && rng.below(h + w) < h);
+        at = if down {
+            (at.0 + 1, at.1)
+        } else {
+            (at.0, at.1 + 1)
+        };
+    }
+    map[start] = 'S';
+    map[end] = 'E';
+    map.to_string() + "\n"
+}
+
+crate::solution::solution!(HillMap);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task12/example.txt").unwrap()).unwrap()),
+            31,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task12/example.txt").unwrap()).unwrap()),
+            29,
+        );
+    }
+}
@@ -1,15 +1,20 @@
+use crate::error::{ParseError, ParseResult};
+use crate::gen::Rng;
 use json::{array, JsonValue};
 use std::{cmp::Ordering, vec::Vec};
 
-fn parse_input(inp: &str) -> Vec<(JsonValue, JsonValue)> {
+fn packet(line: &str) -> ParseResult<JsonValue> {
+    json::parse(line).map_err(|e| ParseError::new(line, format!("Invalid packet: {e}")))
+}
+
+pub fn parse(inp: &str) -> ParseResult<Vec<(JsonValue, JsonValue)>> {
     inp.trim()
         .split("\n\n")
         .map(|block| {
-            let (left, right) = block.split_once('\n').expect("Should be 2 lines");
-            (
-                json::parse(left).expect("Parse failed"),
-                json::parse(right).expect("Parse failed"),
-            )
+            let (left, right) = block
+                .split_once('\n')
+                .ok_or_else(|| ParseError::new(block, "Expected a pair of packets"))?;
+            Ok((packet(left)?, packet(right)?))
         })
         .collect()
 }
@@ -43,8 +48,8 @@ fn find_insertion_pos(pairs: &[&JsonValue], target: &JsonValue) -> usize {
         .count()
 }
 
-pub fn prob1(inp: &str) -> usize {
-    parse_input(inp)
+pub fn part1(pairs: &[(JsonValue, JsonValue)]) -> usize {
+    pairs
         .iter()
         .enumerate()
         .map(|(i, (left, right))| {
@@ -56,8 +61,7 @@ pub fn prob1(inp: &str) -> usize {
         })
         .sum()
 }
-pub fn prob2(inp: &str) -> usize {
-    let pairs = parse_input(inp);
+pub fn part2(pairs: &[(JsonValue, JsonValue)]) -> usize {
     let pairs: Vec<_> = pairs
         .iter()
         .flat_map(|(left, right)| [left, right])
@@ -67,15 +71,37 @@ pub fn prob2(inp: &str) -> usize {
     (1 + find_insertion_pos(&pairs, &a)) * (find_insertion_pos(&pairs, &b) + 2)
 }
 
+fn random_packet(rng: &mut Rng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.range(0..=4))
+        .map(|_| {
+            if depth < 4 && rng.chance(0.3) {
+                random_packet(rng, depth + 1)
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let pairs: Vec<String> = (0..size.max(1))
+        .map(|_| format!("{}\n{}\n", random_packet(rng, 0), random_packet(rng, 0)))
+        .collect();
+    pairs.join("\n")
+}
+
+crate::solution::solution!(Vec<(JsonValue, JsonValue)>);
+
 #[cfg(test)]
 mod tests {
-    use crate::{prob1, prob2};
+    use super::{parse, part1, part2};
     use std::fs;
 
     #[test]
     fn part_1_example() {
         assert_eq!(
-            prob1(&fs::read_to_string("inputs/task13/example.txt").unwrap()),
+            part1(&parse(&fs::read_to_string("inputs/2022/task13/example.txt").unwrap()).unwrap()),
             13,
         );
     }
@@ -83,7 +109,7 @@ mod tests {
     #[test]
     fn part_2_example() {
         assert_eq!(
-            prob2(&fs::read_to_string("inputs/task13/example.txt").unwrap()),
+            part2(&parse(&fs::read_to_string("inputs/2022/task13/example.txt").unwrap()).unwrap()),
             140,
         );
     }
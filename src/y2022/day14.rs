@@ -0,0 +1,196 @@
+use crate::error::{field, ParseError, ParseResult};
+use crate::frames::{self, Colour, Frame};
+use crate::gen::Rng;
+use crate::geometry::{Bounds, Point};
+use crate::grid::Grid;
+use itertools::Itertools;
+use std::fmt::{self, Display};
+use std::vec::Vec;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum CellType {
+    #[default]
+    Empty,
+    Sand,
+    Rock,
+    Start,
+}
+
+impl Display for CellType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Self::Empty => '.',
+            Self::Sand => 'o',
+            Self::Rock => '#',
+            Self::Start => '+',
+        };
+        write!(f, "{c}")
+    }
+}
+
+const SOURCE: Point = Point::new(500, 0);
+const PALETTE: &[(char, Colour)] = &[
+    ('o', [230, 190, 90]),
+    ('#', [130, 120, 110]),
+    ('+', [255, 80, 60]),
+];
+
+// Only the columns sand can reach, the cave is mostly empty to their left
+fn show(grid: &Grid<CellType>, step: usize) {
+    if !frames::enabled() {
+        return;
+    }
+    let (rows, cols) = grid.size();
+    let x = SOURCE.x as usize;
+    let window = grid.crop(0..rows, x.saturating_sub(rows)..(x + rows + 1).min(cols));
+    frames::emit(Frame::from_display(step, &window, PALETTE));
+}
+
+fn parse_point(pair: &str) -> ParseResult<Point> {
+    let (x, y) = pair
+        .split_once(',')
+        .ok_or_else(|| ParseError::new(pair, "Expected a point"))?;
+    Ok(Point::new(
+        field::<usize>(x, "coordinate")? as isize,
+        field::<usize>(y, "coordinate")? as isize,
+    ))
+}
+
+pub fn parse(inp: &str) -> ParseResult<Grid<CellType>> {
+    // Every point with its text, for the errors
+    let data: Vec<Vec<(&str, Point)>> = inp
+        .trim()
+        .split('\n')
+        .map(|row| {
+            row.split(" -> ")
+                .map(|pair| Ok((pair, parse_point(pair)?)))
+                .collect()
+        })
+        .collect::<ParseResult<_>>()?;
+
+    let points = data.iter().flatten().map(|&(_, p)| p).chain([SOURCE]);
+    let bounds = Bounds::from_points(points).ok_or_else(|| ParseError::new(inp, "No paths"))?;
+    let mut grid = Grid::new(bounds.max.y as usize + 1, bounds.max.x as usize + 1);
+    for row in &data {
+        for (&(_, from), &(text, to)) in row.iter().tuple_windows() {
+            if from.x != to.x && from.y != to.y {
+                return Err(ParseError::new(text, "Paths go straight, not diagonally"));
+            }
+            let outside = || ParseError::new(text, "Point outside the cave");
+            let step = (to - from).signum();
+            let mut at = from;
+            grid[at.to_pos().ok_or_else(outside)?] = CellType::Rock;
+            while at != to {
+                at += step;
+                grid[at.to_pos().ok_or_else(outside)?] = CellType::Rock;
+            }
+        }
+    }
+    grid[SOURCE.to_pos().expect("The source is inside the cave")] = CellType::Start;
+    Ok(grid)
+}
+
+fn process(grid: &mut Grid<CellType>) -> usize {
+    let (max_row, max_col) = grid.size();
+    let mut steps = 0usize;
+    loop {
+        steps += 1;
+        let (mut row, mut col) = SOURCE.to_pos().unwrap();
+        let mut stepped = false;
+        loop {
+            row += 1;
+            if row >= max_row || col >= max_col {
+                return steps;
+            } else if grid[(row, col)] == CellType::Empty {
+                // continue;
+            } else if col.checked_sub(1).and_then(|c| grid.get((row, c))) == Some(&CellType::Empty)
+            {
+                col -= 1;
+            } else if grid.get((row, col + 1)) == Some(&CellType::Empty) {
+                col += 1;
+            } else {
+                grid[(row - 1, col)] = CellType::Sand;
+                show(grid, steps);
+                break;
+            }
+            stepped = true;
+        }
+        if !stepped {
+            break steps;
+        }
+    }
+}
+
+pub fn part1(grid: &Grid<CellType>) -> usize {
+    process(&mut grid.clone()) - 1
+}
+
+pub fn part2(grid: &Grid<CellType>) -> usize {
+    let mut grid = grid.clone();
+    let max_row = grid.rows();
+    grid.grow(0, 1, 0, max_row + 1, CellType::Empty);
+    grid.grow(0, 1, 0, 0, CellType::Rock);
+    process(&mut grid)
+}
+
+// `size` paths of straight lines below the source, in the band of the cave
+// that the sand can reach
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let depth: isize = rng.range(10..=(size as isize * 2).clamp(10, 170));
+    let (left, right) = (SOURCE.x - depth, SOURCE.x + depth);
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let mut at = Point::new(rng.range(left..=right), rng.range(1..=depth));
+        let mut path = vec![at];
+        for i in 0..rng.range(1..=5) {
+            let len = rng.range(1..=8) * if rng.chance(0.5) { 1 } else { -1 };
+            let step = if i % 2 == 0 {
+                Point::new(len, 0)
+            } else {
+                Point::new(0, len)
+            };
+            let next = (at + step)
+                .max(Point::new(left, 1))
+                .min(Point::new(right, depth));
+            if next != at {
+                at = next;
+                path.push(at);
+            }
+        }
+        let points: Vec<String> = path.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+        out += &points.join(" -> ");
+        out.push('\n');
+    }
+    out
+}
+
+crate::solution::solution!(Grid<CellType>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task14/example.txt").unwrap()).unwrap()),
+            24,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task14/example.txt").unwrap()).unwrap()),
+            93,
+        );
+    }
+
+    #[test]
+    fn rejects_diagonal_paths() {
+        let inp = "498,4 -> 498,6\n0,0 -> 3,5";
+        let err = parse(inp).unwrap_err().locate(inp);
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 8, "3,5"));
+    }
+}
@@ -0,0 +1,196 @@
+use crate::error::{field, ParseError, ParseResult};
+use crate::gen::Rng;
+use crate::geometry::{Bounds, Point};
+use range_union_find::IntRangeUnionFind;
+use sscanf::sscanf;
+use std::vec::Vec;
+
+#[derive(Debug)]
+pub struct Info {
+    sensor: Point,
+    beacon: Point,
+    distance: usize,
+}
+
+// The first line holds the row of interest, as it differs between example and real input
+pub fn parse(inp: &str) -> ParseResult<(isize, Vec<Info>)> {
+    let mut it = inp.trim().split('\n');
+    Ok((
+        field(it.next().unwrap_or_default(), "row")?,
+        it.map(|row| {
+            let (xs, ys, xb, yb) = sscanf!(
+                row,
+                "Sensor at x={isize}, y={isize}: closest beacon is at x={isize}, y={isize}"
+            )
+            .map_err(|_| ParseError::new(row, "Invalid sensor report"))?;
+            let (sensor, beacon) = (Point::new(xs, ys), Point::new(xb, yb));
+            Ok(Info {
+                sensor,
+                beacon,
+                distance: sensor.manhattan(beacon),
+            })
+        })
+        .collect::<ParseResult<_>>()?,
+    ))
+}
+
+pub fn part1((y, records): &(isize, Vec<Info>)) -> isize {
+    let y = *y;
+    let mut acc = IntRangeUnionFind::new();
+    for r in records.iter() {
+        let dx = r.sensor.y.abs_diff(y) as isize - r.distance as isize;
+        if dx <= 0 {
+            acc.insert_range(&(r.sensor.x + dx..=r.sensor.x - dx))
+                .unwrap();
+        }
+    }
+    for r in records.iter() {
+        if r.beacon.y == y {
+            acc.remove_range_pair(&r.beacon.x, &r.beacon.x).unwrap();
+        }
+    }
+
+    acc.into_collection::<Vec<_>>()
+        .iter()
+        .map(|r| r.end() - r.start() + 1)
+        .sum()
+}
+
+pub fn part2((y0, records): &(isize, Vec<Info>)) -> isize {
+    const SIGNS: [Point; 4] = [
+        Point::new(1, 1),
+        Point::new(1, -1),
+        Point::new(-1, 1),
+        Point::new(-1, -1),
+    ];
+    let bounds = Bounds {
+        min: Point::ZERO,
+        max: Point::ONES * (y0 * 2),
+    };
+    for r in records.iter() {
+        for dx in 0..=r.distance + 1 {
+            let dy = (r.distance + 1 - dx) as isize;
+            for sign in SIGNS {
+                let d = Point::new(dx as isize * sign.x, dy * sign.y);
+                let p = r.sensor + d;
+                if bounds.contains(p)
+                    && !records
+                        .iter()
+                        .any(|r2| r2.sensor.manhattan(p) <= r2.distance)
+                {
+                    return p.y + p.x * 4_000_000;
+                }
+            }
+        }
+    }
+    panic!("Failed to find");
+}
+
+// Sensors on a lattice cover the whole search area. The ones reaching a
+// hidden point shrink to leave it out, and new sensors fill all the other
+// gaps this leaves, so the hidden point is the only one left.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let k = ((size as f64).sqrt().ceil() as isize).max(2);
+    let spacing = rng.range(4..=12);
+    let y0 = (k - 1) * spacing / 2;
+    let area = Bounds {
+        min: Point::ZERO,
+        max: Point::ONES * (y0 * 2),
+    };
+    let hidden = loop {
+        let p = Point::new(rng.range(0..=y0 * 2), rng.range(0..=y0 * 2));
+        if p.x % spacing != 0 || p.y % spacing != 0 {
+            break p;
+        }
+    };
+    let mut sensors: Vec<(Point, usize)> = (0..k * k)
+        .map(|i| {
+            let sensor = Point::new(i % k, i / k) * spacing;
+            (sensor, (spacing as usize).min(sensor.manhattan(hidden) - 1))
+        })
+        .collect();
+    let near = Bounds {
+        min: hidden,
+        max: hidden,
+    }
+    .grow(spacing * 2);
+    for y in near.min.y..=near.max.y {
+        for x in near.min.x..=near.max.x {
+            let p = Point::new(x, y);
+            if p != hidden && area.contains(p) && !sensors.iter().any(|&(s, r)| s.manhattan(p) <= r)
+            {
+                sensors.push((p, p.manhattan(hidden) - 1));
+            }
+        }
+    }
+
+    let mut out = format!("{y0}\n");
+    for (sensor, r) in sensors {
+        let dx = rng.range(-(r as isize)..=r as isize);
+        let dy = (r as isize - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+        let beacon = sensor + Point::new(dx, dy);
+        out += &format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+            sensor.x, sensor.y, beacon.x, beacon.y
+        );
+    }
+    out
+}
+
+crate::solution::solution!((isize, Vec<Info>));
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, parse, part1, part2, Info};
+    use crate::gen::Rng;
+    use crate::geometry::Point;
+    use std::fs;
+
+    // Checks every position of the row and of the search area one by one,
+    // the hidden beacon has to be the only uncovered position
+    fn naive((y0, records): &(isize, Vec<Info>)) -> (isize, isize) {
+        let covered = |p: Point| records.iter().any(|r| r.sensor.manhattan(p) <= r.distance);
+        let reach = records.iter().map(|r| r.distance as isize).max().unwrap();
+        let (min_x, max_x) = records
+            .iter()
+            .fold((isize::MAX, isize::MIN), |(lo, hi), r| {
+                (lo.min(r.sensor.x), hi.max(r.sensor.x))
+            });
+        let row = (min_x - reach..=max_x + reach)
+            .map(|x| Point::new(x, *y0))
+            .filter(|&p| covered(p) && records.iter().all(|r| r.beacon != p))
+            .count();
+        let hidden: Vec<_> = (0..=y0 * 2)
+            .flat_map(|y| (0..=y0 * 2).map(move |x| Point::new(x, y)))
+            .filter(|&p| !covered(p))
+            .collect();
+        assert_eq!(hidden.len(), 1, "{hidden:?}");
+        (row as isize, hidden[0].y + hidden[0].x * 4_000_000)
+    }
+
+    #[test]
+    fn matches_naive_on_random_inputs() {
+        let mut rng = Rng::new(15);
+        for _ in 0..20 {
+            let size = rng.range(1..=30);
+            let inp = parse(&generate(&mut rng, size)).unwrap();
+            assert_eq!((part1(&inp), part2(&inp)), naive(&inp));
+        }
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task15/example.txt").unwrap()).unwrap()),
+            26,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task15/example.txt").unwrap()).unwrap()),
+            56000011,
+        );
+    }
+}
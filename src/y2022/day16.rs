@@ -0,0 +1,227 @@
+use crate::error::{ParseError, ParseResult};
+use crate::gen::Rng;
+use sscanf::sscanf;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::iter;
+use std::vec::Vec;
+
+#[derive(Debug)]
+pub struct Valve {
+    rate: u32,
+    targets: Vec<u64>,
+}
+
+#[derive(Debug)]
+struct State {
+    who: u64,
+    opened_mask: u64,
+    pressure: u32,
+}
+
+type Indices = HashMap<String, u64>;
+
+pub fn parse(inp: &str) -> ParseResult<(HashMap<u64, Valve>, Indices)> {
+    let mut indices = Indices::new();
+    let rows = inp
+        .trim()
+        .split('\n')
+        .map(|row| {
+            let (name, rate, _, _, _, targets) = sscanf!(
+                row,
+                "Valve {str} has flow rate={u32}; tunnel{str}lead{str}to {str} {str}"
+            )
+            .map_err(|_| ParseError::new(row, "Invalid valve description"))?;
+            if indices.len() == 64 {
+                return Err(ParseError::new(row, "Too many valves"));
+            }
+            let i = 1u64 << indices.len();
+            indices.insert(name.to_string(), i);
+            Ok((i, rate, targets.split(", ").collect::<Vec<_>>()))
+        })
+        .collect::<ParseResult<Vec<_>>>()?;
+    let graph = rows
+        .iter()
+        .map(|(i, rate, targets)| {
+            let targets = targets
+                .iter()
+                .map(|t| {
+                    indices
+                        .get(*t)
+                        .copied()
+                        .ok_or_else(|| ParseError::new(t, "Unknown valve"))
+                })
+                .collect::<ParseResult<_>>()?;
+            Ok((
+                *i,
+                Valve {
+                    rate: *rate,
+                    targets,
+                },
+            ))
+        })
+        .collect::<ParseResult<_>>()?;
+    if !indices.contains_key("AA") {
+        return Err(ParseError::new(inp, "No valve AA"));
+    }
+    Ok((graph, indices))
+}
+
+fn solve(graph: &HashMap<u64, Valve>, indices: &Indices, steps: u32) -> HashMap<(u64, u64), u32> {
+    assert!(graph.len() <= 64, "Does not fir into u64 mask");
+    let mut best = HashMap::<(u64, u64), u32>::new();
+    let mut states = vec![State {
+        who: indices["AA"],
+        opened_mask: 0,
+        pressure: 0,
+    }];
+    for t in 1..=steps {
+        states = states
+            .iter()
+            .filter_map(|s| {
+                let key = (s.who, s.opened_mask);
+                if best.contains_key(&key) && s.pressure <= best[&key] {
+                    return None;
+                }
+                best.insert(key, s.pressure);
+
+                let Valve { rate, targets, .. } = &graph[&s.who];
+                Some(
+                    iter::once(if s.opened_mask & s.who == 0 && *rate > 0 {
+                        Some(State {
+                            who: s.who,
+                            opened_mask: s.opened_mask | s.who,
+                            pressure: s.pressure + rate * (steps - t),
+                        })
+                    } else {
+                        None
+                    })
+                    .chain(targets.iter().map(|dest| {
+                        Some(State {
+                            who: *dest,
+                            opened_mask: s.opened_mask,
+                            pressure: s.pressure,
+                        })
+                    })),
+                )
+            })
+            .flatten()
+            .flatten()
+            .collect();
+    }
+    best
+}
+
+pub fn part1((graph, indices): &(HashMap<u64, Valve>, Indices)) -> u32 {
+    *solve(graph, indices, 30).values().max().expect("Not empty")
+}
+pub fn part2((graph, indices): &(HashMap<u64, Valve>, Indices)) -> u32 {
+    let best = solve(graph, indices, 26);
+    let mut best = best.iter().collect::<Vec<_>>();
+    best.sort_by_key(|(_, v)| Reverse(*v));
+
+    let mut a = 0;
+    let mut best_2 = 0;
+    'outer: for ((_, m2), v2) in &best {
+        for ((_, m1), v1) in &best {
+            if **v1 <= best_2 {
+                break;
+            }
+            if m1 & m2 == 0 {
+                a = a.max(**v1 + **v2);
+                best_2 = **v1;
+                if v1 > v2 {
+                    break 'outer;
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+    a
+}
+
+// Like the real input: AA and up to 15 working valves are linked by corridors
+// of stuck valves, every one of them with two tunnels
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n = size.clamp(2, 64);
+    let mut names = vec!["AA".to_string()];
+    while names.len() < n {
+        let name: String = (0..2).map(|_| rng.range(b'A'..=b'Z') as char).collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let rooms = ((n - 1) / 4).clamp(1, 15) + 1;
+    let mut rates = vec![0; n];
+    for rate in &mut rates[1..rooms] {
+        *rate = rng.range(1..=25);
+    }
+
+    let mut links = vec![];
+    for room in 1..rooms {
+        links.push((room, rng.below(room)));
+    }
+    for _ in 0..rooms / 2 {
+        let (a, b) = (rng.below(rooms), rng.below(rooms));
+        if a != b && !links.contains(&(a, b)) && !links.contains(&(b, a)) {
+            links.push((a, b));
+        }
+    }
+    let mut corridors = vec![vec![]; links.len()];
+    for valve in rooms..n {
+        corridors[rng.below(links.len())].push(valve);
+    }
+    let mut tunnels: Vec<Vec<usize>> = vec![vec![]; n];
+    for (&(a, b), corridor) in links.iter().zip(corridors) {
+        let path: Vec<usize> = [a].into_iter().chain(corridor).chain([b]).collect();
+        for pair in path.windows(2) {
+            tunnels[pair[0]].push(pair[1]);
+            tunnels[pair[1]].push(pair[0]);
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut order);
+    order
+        .into_iter()
+        .map(|valve| {
+            let targets: Vec<&str> = tunnels[valve].iter().map(|&t| names[t].as_str()).collect();
+            let tunnels = if targets.len() == 1 {
+                "tunnel leads to valve"
+            } else {
+                "tunnels lead to valves"
+            };
+            format!(
+                "Valve {} has flow rate={}; {tunnels} {}\n",
+                names[valve],
+                rates[valve],
+                targets.join(", ")
+            )
+        })
+        .collect()
+}
+
+crate::solution::solution!((HashMap<u64, Valve>, Indices));
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task16/example.txt").unwrap()).unwrap()),
+            1651,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task16/example.txt").unwrap()).unwrap()),
+            1707,
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use crate::cycle::CycleFinder;
+use crate::error::{ParseError, ParseResult};
+use crate::frames::{self, Colour, Frame};
+use crate::gen::Rng;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::search;
+use std::vec::Vec;
+
+const WIDTH: usize = 7;
+// Offsets from the bottom left corner, y grows upwards
+const ROCKS: [&[Point]; 5] = [
+    &[
+        Point::new(3, 0),
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+    ],
+    &[
+        Point::new(2, 1),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 2),
+    ],
+    &[
+        Point::new(2, 2),
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(2, 1),
+    ],
+    &[
+        Point::new(0, 0),
+        Point::new(0, 1),
+        Point::new(0, 2),
+        Point::new(0, 3),
+    ],
+    &[
+        Point::new(1, 1),
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(0, 1),
+    ],
+];
+const EMPTY: [u8; WIDTH] = [0, 0, 0, 0, 0, 0, 0];
+const FALL: Point = Point::new(0, -1);
+// Rows below the top that make up the state of the tower
+const DEPTH: isize = 64;
+
+const PALETTE: &[(char, Colour)] = &[('@', [240, 120, 40]), ('#', [140, 140, 150])];
+// Rows of the tower in a frame
+const SHOWN: usize = 40;
+
+// The top of the tower between its walls, with the last rock as `@`
+fn show(map: &[[u8; WIDTH]], rock: &[Point], at: Point, step: usize) {
+    if !frames::enabled() {
+        return;
+    }
+    let hmax = get_height(map);
+    let rows: Vec<usize> = (hmax.saturating_sub(SHOWN)..hmax).rev().collect();
+    let floor = hmax <= SHOWN;
+    let mut cells = Grid::new(rows.len() + floor as usize, WIDTH + 2);
+    for (r, &y) in rows.iter().enumerate() {
+        cells[(r, 0)] = '|';
+        cells[(r, WIDTH + 1)] = '|';
+        for x in 0..WIDTH {
+            let resting = rock
+                .iter()
+                .any(|&d| at + d == Point::new(x as isize, y as isize));
+            cells[(r, x + 1)] = match map[y][x] {
+                _ if resting => '@',
+                b'#' => '#',
+                _ => '.',
+            };
+        }
+    }
+    if floor {
+        for c in 0..WIDTH + 2 {
+            cells[(rows.len(), c)] = if c == 0 || c == WIDTH + 1 { '+' } else { '-' };
+        }
+    }
+    frames::emit(Frame::new(step, cells, PALETTE));
+}
+
+fn get_height(map: &[[u8; WIDTH]]) -> usize {
+    let len = map.len();
+    len - map
+        .iter()
+        .rev()
+        .position(|row| row != &EMPTY)
+        .unwrap_or(len)
+}
+
+fn can_fit(map: &[[u8; WIDTH]], rock: &[Point], at: Point) -> bool {
+    rock.iter().all(|&d| {
+        let p = at + d;
+        (0..WIDTH as isize).contains(&p.x) && p.y >= 0 && map[p.y as usize][p.x as usize] != b'#'
+    })
+}
+
+// Air that a falling rock can still reach, relative to the top of the tower
+fn surface(map: &[[u8; WIDTH]], hmax: usize) -> Vec<Point> {
+    let top = Point::new(0, hmax as isize);
+    let bottom = (top.y - DEPTH).max(0);
+    let mut air = search::flood_fill(top, |&p| {
+        [Point::new(-1, 0), Point::new(1, 0), FALL]
+            .into_iter()
+            .map(move |d| p + d)
+            .filter(|p| {
+                (0..WIDTH as isize).contains(&p.x)
+                    && p.y >= bottom
+                    && map
+                        .get(p.y as usize)
+                        .is_none_or(|row| row[p.x as usize] != b'#')
+            })
+    });
+    air.iter_mut().for_each(|p| *p -= top);
+    air.sort();
+    air
+}
+
+pub fn parse(inp: &str) -> ParseResult<String> {
+    let jets = inp.trim();
+    if jets.is_empty() {
+        return Err(ParseError::new(inp, "No jets"));
+    }
+    match jets.char_indices().find(|&(_, c)| c != '<' && c != '>') {
+        Some((i, c)) => Err(ParseError::new(&jets[i..i + c.len_utf8()], "Unknown jet")),
+        None => Ok(jets.to_string()),
+    }
+}
+
+pub fn part1(jets: &str) -> i64 {
+    tower_height(jets, 2022)
+}
+pub fn part2(jets: &str) -> i64 {
+    tower_height(jets, 1_000_000_000_000)
+}
+
+// Height of the tower after `rocks` rocks have fallen
+pub fn tower_height(inp: &str, rocks_count: usize) -> i64 {
+    let mut map: Vec<[u8; WIDTH]> = vec![];
+    let mut jets = inp.as_bytes().iter().enumerate().cycle();
+    let mut rocks = ROCKS.iter().enumerate().cycle();
+    // Step `i` is the state after `i + 1` rocks
+    let mut finder = CycleFinder::new();
+
+    for step in 1..=rocks_count {
+        let mut jet_index;
+        let (rock_index, rock) = rocks.next().unwrap();
+        let h = get_height(&map);
+        // Room for the tallest rock
+        map.resize(h + 7, EMPTY);
+        let mut at = Point::new(2, h as isize + 3);
+        loop {
+            let jet;
+            (jet_index, jet) = jets.next().unwrap();
+            let push = match jet {
+                b'>' => Point::new(1, 0),
+                b'<' => Point::new(-1, 0),
+                _ => panic!("Unknown"),
+            };
+            if can_fit(&map, rock, at + push) {
+                at += push;
+            }
+            if !can_fit(&map, rock, at + FALL) {
+                break;
+            }
+            at += FALL;
+        }
+        for &d in rock.iter() {
+            let p = at + d;
+            map[p.y as usize][p.x as usize] = b'#';
+        }
+        show(&map, rock, at, step);
+
+        let hmax = get_height(&map);
+        let key = (rock_index, jet_index, surface(&map, hmax));
+        if finder.push(key, hmax as i64).is_some() {
+            return finder.extrapolate(rocks_count - 1).unwrap();
+        }
+    }
+    get_height(&map) as i64
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let jets: String = (0..size.max(1)).map(|_| *rng.pick(&['<', '>'])).collect();
+    jets + "\n"
+}
+
+crate::solution::solution!(String);
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, parse, part1, part2, tower_height, FALL, ROCKS, WIDTH};
+    use crate::gen::Rng;
+    use crate::geometry::Point;
+    use std::fs;
+
+    // Every rock on an unbounded well, without skipping cycles
+    fn naive_height(jets: &str, rocks: usize) -> i64 {
+        let mut rows: Vec<[bool; WIDTH]> = vec![];
+        let mut jets = jets.bytes().cycle();
+        for rock in ROCKS.iter().cycle().take(rocks) {
+            let fits = |rows: &[[bool; WIDTH]], at: Point| {
+                rock.iter().all(|&d| {
+                    let p = at + d;
+                    (0..WIDTH as isize).contains(&p.x)
+                        && p.y >= 0
+                        && !rows.get(p.y as usize).is_some_and(|row| row[p.x as usize])
+                })
+            };
+            let mut at = Point::new(2, rows.len() as isize + 3);
+            loop {
+                let push = match jets.next() {
+                    Some(b'>') => Point::new(1, 0),
+                    _ => Point::new(-1, 0),
+                };
+                if fits(&rows, at + push) {
+                    at += push;
+                }
+                if !fits(&rows, at + FALL) {
+                    break;
+                }
+                at += FALL;
+            }
+            for &d in rock.iter() {
+                let p = at + d;
+                if rows.len() <= p.y as usize {
+                    rows.resize(p.y as usize + 1, [false; WIDTH]);
+                }
+                rows[p.y as usize][p.x as usize] = true;
+            }
+        }
+        rows.len() as i64
+    }
+
+    #[test]
+    fn matches_naive_on_random_inputs() {
+        let mut rng = Rng::new(17);
+        for _ in 0..30 {
+            let size = rng.range(1..=40);
+            let jets = parse(&generate(&mut rng, size)).unwrap();
+            let rocks = rng.range(1..=5000);
+            assert_eq!(
+                tower_height(&jets, rocks),
+                naive_height(&jets, rocks),
+                "{rocks} rocks, jets {jets}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(parse("\n").unwrap_err().message, "No jets");
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(
+                &parse(
+                    fs::read_to_string("inputs/2022/task17/example.txt")
+                        .unwrap()
+                        .strip_suffix('\n')
+                        .unwrap()
+                )
+                .unwrap()
+            ),
+            3068,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(
+                &parse(
+                    fs::read_to_string("inputs/2022/task17/example.txt")
+                        .unwrap()
+                        .strip_suffix('\n')
+                        .unwrap()
+                )
+                .unwrap()
+            ),
+            1514285714288,
+        );
+    }
+}
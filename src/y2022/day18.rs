@@ -0,0 +1,159 @@
+use crate::error::{field, ParseError, ParseResult};
+use crate::gen::Rng;
+use crate::geometry::{Bounds, Point3};
+use crate::search;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::vec::Vec;
+
+fn parse_point(s: &str) -> ParseResult<Point3> {
+    if let Some((x, y, z)) = s.split(',').collect_tuple() {
+        Ok(Point3::new(
+            field(x, "coordinate")?,
+            field(y, "coordinate")?,
+            field(z, "coordinate")?,
+        ))
+    } else {
+        Err(ParseError::new(s, "Expected three coordinates"))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Cell {
+    is_lava: bool,
+}
+
+#[derive(Debug)]
+struct Map {
+    map: Vec<Vec<Vec<Cell>>>,
+    // Includes one cell of air from every side
+    bounds: Bounds<Point3>,
+}
+impl Map {
+    fn at(&mut self, index: Point3) -> &mut Cell {
+        let index = index - self.bounds.min;
+        &mut self.map[index.z as usize][index.y as usize][index.x as usize]
+    }
+    fn checked_at(&self, index: Point3) -> Option<&Cell> {
+        if !self.bounds.contains(index) {
+            return None;
+        }
+        let index = index - self.bounds.min;
+        Some(&self.map[index.z as usize][index.y as usize][index.x as usize])
+    }
+    fn to_fit(trues: &[Point3]) -> Self {
+        let bounds = Bounds::from_points(trues.iter().copied())
+            .expect("Non-empty")
+            .grow(1);
+        let size = bounds.size();
+        Self {
+            map: vec![
+                vec![vec![Cell::default(); size.x as usize]; size.y as usize];
+                size.z as usize
+            ],
+            bounds,
+        }
+    }
+}
+
+pub fn parse(inp: &str) -> ParseResult<Vec<Point3>> {
+    let cubes: Vec<_> = inp.lines().map(parse_point).collect::<ParseResult<_>>()?;
+    if cubes.is_empty() {
+        return Err(ParseError::new(inp, "No cubes"));
+    }
+    Ok(cubes)
+}
+
+pub fn part1(cubes: &[Point3]) -> usize {
+    let mut map = Map::to_fit(cubes);
+    6 * cubes.len()
+        - cubes
+            .iter()
+            .map(|cube| {
+                map.at(*cube).is_lava = true;
+                cube.neighbours6().filter(|&n| map.at(n).is_lava).count()
+            })
+            .sum::<usize>()
+            * 2
+}
+
+pub fn part2(cubes: &[Point3]) -> usize {
+    let mut map = Map::to_fit(cubes);
+    cubes.iter().for_each(|&cube| map.at(cube).is_lava = true);
+
+    // Air around the droplet, starting from a corner of the padded map
+    let start = map.bounds.min;
+    let is_lava = |point: Point3| map.checked_at(point).map(|el| el.is_lava);
+    let air = search::flood_fill(start, |current| {
+        current
+            .neighbours6()
+            .filter(|&neighbour| is_lava(neighbour) == Some(false))
+            .collect::<Vec<_>>()
+    });
+    air.iter()
+        .map(|current| {
+            current
+                .neighbours6()
+                .filter(|&neighbour| is_lava(neighbour) == Some(true))
+                .count()
+        })
+        .sum()
+}
+
+// `size` different cubes packed into a box about twice their volume
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n = size.max(1);
+    let side = ((2 * n) as f64).cbrt().ceil() as isize;
+    let mut cubes = HashSet::new();
+    let mut out = String::new();
+    while cubes.len() < n {
+        let [x, y, z] = [(); 3].map(|_| rng.range(0..=side));
+        if cubes.insert(Point3::new(x, y, z)) {
+            out += &format!("{x},{y},{z}\n");
+        }
+    }
+    out
+}
+
+crate::solution::solution!(Vec<Point3>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(parse("").unwrap_err().message, "No cubes");
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(
+                &parse(
+                    fs::read_to_string("inputs/2022/task18/example.txt")
+                        .unwrap()
+                        .trim()
+                )
+                .unwrap()
+            ),
+            64,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(
+                &parse(
+                    fs::read_to_string("inputs/2022/task18/example.txt")
+                        .unwrap()
+                        .trim()
+                )
+                .unwrap()
+            ),
+            58,
+        );
+    }
+}
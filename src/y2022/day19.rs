@@ -1,4 +1,8 @@
+use crate::error::{ParseError, ParseResult};
+use crate::gen::Rng;
+use crate::trace;
 use hashbrown::HashSet;
+use itertools::Itertools;
 use rustc_hash::FxHasher as Hasher;
 use sscanf::sscanf;
 use std::hash::BuildHasherDefault;
@@ -13,10 +17,11 @@ struct State {
     bought: Option<u8>,
 }
 
-fn parse_input(inp: &str) -> Vec<[[i16; 4]; 4]> {
-    inp.split('\n')
-        .map(|row| sscanf!(row, "Blueprint {usize}: Each ore robot costs {i16} ore. Each clay robot costs {i16} ore. Each obsidian robot costs {i16} ore and {i16} clay. Each geode robot costs {i16} ore and {i16} obsidian.").unwrap())
-        .map(|(_, ore, clay, obs_1, obs_2, geo_1, geo_2)| [
+pub fn parse(inp: &str) -> ParseResult<Vec<[[i16; 4]; 4]>> {
+    inp.lines()
+        .map(|row| sscanf!(row, "Blueprint {usize}: Each ore robot costs {i16} ore. Each clay robot costs {i16} ore. Each obsidian robot costs {i16} ore and {i16} clay. Each geode robot costs {i16} ore and {i16} obsidian.")
+            .map_err(|_| ParseError::new(row, "Invalid blueprint")))
+        .map_ok(|(_, ore, clay, obs_1, obs_2, geo_1, geo_2)| [
             [ore, 0, 0, 0],
             [clay, 0, 0, 0],
             [obs_1, obs_2, 0, 0],
@@ -123,55 +128,130 @@ fn score(blueprint: &[[i16; 4]], steps: i16) -> i16 {
                 bought: None,
             });
         }
-        println!("Step {step} done, with {} states", states.len());
+        trace::event!(Info, "step", step = step, states = states.len());
     }
     states.iter().map(|s| s.balance[3]).max().unwrap()
 }
 
-pub fn prob1(inp: &str) -> i16 {
-    let blueprints = parse_input(inp);
+pub fn part1(blueprints: &[[[i16; 4]; 4]]) -> i16 {
     blueprints
-        .into_iter()
+        .iter()
         .enumerate()
-        .map(|(i, b)| (i + 1) as i16 * score(&b, 24))
+        .map(|(i, b)| (i + 1) as i16 * score(b, 24))
         .sum()
 }
 
-pub fn prob2(inp: &str) -> i16 {
-    let blueprints = parse_input(inp);
-    blueprints
-        .into_iter()
-        .take(3)
-        .map(|b| score(&b, 32))
-        .product()
+pub fn part2(blueprints: &[[[i16; 4]; 4]]) -> i16 {
+    blueprints.iter().take(3).map(|b| score(b, 32)).product()
 }
 
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (1..=size.clamp(1, 30))
+        .map(|i| {
+            format!(
+                "Blueprint {i}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. \
+                 Each geode robot costs {} ore and {} obsidian.\n",
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(5..=20),
+                rng.range(2..=4),
+                rng.range(5..=20),
+            )
+        })
+        .collect()
+}
+
+crate::solution::solution!(Vec<[[i16; 4]; 4]>);
+
 #[cfg(test)]
 mod tests {
-    use crate::{prob1, prob2};
+    use super::{generate, parse, part1, part2, score};
+    use crate::gen::Rng;
     use std::fs;
 
+    // Tries every order of robots, each one built as soon as it is affordable
+    fn naive(blueprint: &[[i16; 4]], steps: i16, robots: [i16; 4], balance: [i16; 4]) -> i16 {
+        let mut best = balance[3] + robots[3] * steps;
+        for (i, cost) in blueprint.iter().enumerate() {
+            let wait = (0..4)
+                .map(|r| match (cost[r] - balance[r]).max(0) {
+                    0 => Some(0),
+                    _ if robots[r] == 0 => None,
+                    missing => Some((missing + robots[r] - 1) / robots[r]),
+                })
+                .try_fold(0, |acc, w| w.map(|w| acc.max(w)));
+            let Some(wait) = wait.filter(|&w| w < steps) else {
+                continue;
+            };
+            let mut balance = balance;
+            for r in 0..4 {
+                balance[r] += robots[r] * (wait + 1) - cost[r];
+            }
+            let mut robots = robots;
+            robots[i] += 1;
+            best = best.max(naive(blueprint, steps - wait - 1, robots, balance));
+        }
+        best
+    }
+
+    #[test]
+    fn matches_naive_on_random_inputs() {
+        let mut rng = Rng::new(19);
+        for _ in 0..30 {
+            // Cheaper than generated ones, so that a few geodes open in fewer steps
+            let blueprint = [
+                [rng.range(1..=4), 0, 0, 0],
+                [rng.range(1..=4), 0, 0, 0],
+                [rng.range(1..=4), rng.range(1..=6), 0, 0],
+                [rng.range(1..=4), 0, rng.range(1..=6), 0],
+            ];
+            let steps = rng.range(8..=16);
+            assert_eq!(
+                score(&blueprint, steps),
+                naive(&blueprint, steps, [1, 0, 0, 0], [0; 4]),
+                "{blueprint:?} in {steps} steps"
+            );
+        }
+        // Generated blueprints, mostly without geodes in that time
+        let blueprints = parse(&generate(&mut rng, 3)).unwrap();
+        for blueprint in &blueprints {
+            assert_eq!(
+                score(blueprint, 18),
+                naive(blueprint, 18, [1, 0, 0, 0], [0; 4])
+            );
+        }
+    }
+
     #[test]
     fn part_1_example() {
         assert_eq!(
-            prob1(
-                &fs::read_to_string("inputs/task19/example.txt")
-                    .unwrap()
-                    .trim()
+            part1(
+                &parse(
+                    fs::read_to_string("inputs/2022/task19/example.txt")
+                        .unwrap()
+                        .trim()
+                )
+                .unwrap()
             ),
             33,
         );
     }
 
     #[test]
+    #[ignore = "takes minutes and several GB of memory, run with `cargo test --release -- --ignored`"]
     fn part_2_example() {
         assert_eq!(
-            prob2(
-                &fs::read_to_string("inputs/task19/example.txt")
-                    .unwrap()
-                    .trim()
+            part2(
+                &parse(
+                    fs::read_to_string("inputs/2022/task19/example.txt")
+                        .unwrap()
+                        .trim()
+                )
+                .unwrap()
             ),
-            62,
+            56 * 62,
         );
     }
 }
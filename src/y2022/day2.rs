@@ -0,0 +1,105 @@
+use crate::error::{ParseError, ParseResult};
+use crate::gen::Rng;
+
+fn choice_score(x: char) -> Result<u32, &'static str> {
+    match x {
+        'A' | 'X' => Ok(1),
+        'B' | 'Y' => Ok(2),
+        'C' | 'Z' => Ok(3),
+        _ => Err("Unknown!"),
+    }
+}
+
+fn winning_score(first: char, second: char) -> Result<u32, &'static str> {
+    match (first, second) {
+        ('C', 'X') | ('A', 'Y') | ('B', 'Z') => Ok(6),
+        ('A', 'X') | ('B', 'Y') | ('C', 'Z') => Ok(3),
+        ('B', 'X') | ('C', 'Y') | ('A', 'Z') => Ok(0),
+        _ => Err("Unknown"),
+    }
+}
+
+fn get_choice(first: char, goal: char) -> Result<char, &'static str> {
+    match (first, goal) {
+        ('C', 'X') | ('B', 'Y') | ('A', 'Z') => Ok('Y'),
+        ('A', 'X') | ('C', 'Y') | ('B', 'Z') => Ok('Z'),
+        ('B', 'X') | ('A', 'Y') | ('C', 'Z') => Ok('X'),
+        _ => Err("Unknown"),
+    }
+}
+
+fn solve(rounds: &[(char, char)], mutate: &mut dyn FnMut(&mut char, &mut char)) -> u32 {
+    let mut total: u32 = 0;
+    for &(mut t1, mut t2) in rounds {
+        mutate(&mut t1, &mut t2);
+        total += winning_score(t1, t2).expect("Unknown combination")
+            + choice_score(t2).expect("Unknown letter");
+    }
+    total
+}
+
+fn letter(text: &str, allowed: &str) -> ParseResult<char> {
+    match text.chars().next() {
+        Some(c) if text.len() == 1 && allowed.contains(c) => Ok(c),
+        _ => Err(ParseError::new(text, format!("Expected one of {allowed}"))),
+    }
+}
+
+pub fn parse(inp: &str) -> ParseResult<Vec<(char, char)>> {
+    inp.lines()
+        .map(|row| {
+            let (t1, t2) = row
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(row, "Expected two letters"))?;
+            Ok((letter(t1, "ABC")?, letter(t2, "XYZ")?))
+        })
+        .collect()
+}
+
+pub fn part1(rounds: &[(char, char)]) -> u32 {
+    fn noop(_t1: &mut char, _t2: &mut char) {}
+    solve(rounds, &mut noop)
+}
+
+pub fn part2(rounds: &[(char, char)]) -> u32 {
+    fn to_choice(t1: &mut char, t2: &mut char) {
+        *t2 = get_choice(*t1, *t2).expect("Cannot detect winner");
+    }
+    solve(rounds, &mut to_choice)
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.pick(&['A', 'B', 'C']),
+                rng.pick(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
+
+crate::solution::solution!(Vec<(char, char)>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task2/example.txt").unwrap()).unwrap()),
+            15,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task2/example.txt").unwrap()).unwrap()),
+            12,
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use crate::error::{field, ParseError, ParseResult};
+use crate::gen::Rng;
+use std::collections::VecDeque;
+
+pub fn parse(inp: &str) -> ParseResult<VecDeque<(i64, usize)>> {
+    let list = inp
+        .lines()
+        .enumerate()
+        .map(|(i, row)| Ok((field(row, "number")?, i)))
+        .collect::<ParseResult<VecDeque<_>>>()?;
+    if !list.iter().any(|&(x, _)| x == 0) {
+        return Err(ParseError::new(inp, "The list must contain 0"));
+    }
+    Ok(list)
+}
+
+fn solve(list: &mut VecDeque<(i64, usize)>, repeats: usize) -> i64 {
+    let len = list.len();
+    let lm1 = len as i64 - 1;
+    for _ in 0..repeats {
+        for step in 0..len {
+            let index = list.iter().position(|nxt| nxt.1 == step).unwrap();
+            let node = list.remove(index).unwrap();
+            let target = (node.0 + index as i64).rem_euclid(lm1);
+            list.insert(target as usize, node);
+        }
+    }
+
+    let f = list.iter().position(|x| x.0 == 0).unwrap();
+    list[(f + 1000) % len].0 + list[(f + 2000) % len].0 + list[(f + 3000) % len].0
+}
+
+pub fn part1(list: &VecDeque<(i64, usize)>) -> i64 {
+    solve(&mut list.clone(), 1)
+}
+
+pub fn part2(list: &VecDeque<(i64, usize)>) -> i64 {
+    let mut list = list.clone();
+    list.iter_mut().for_each(|(x, _)| *x *= 811_589_153);
+    solve(&mut list, 10)
+}
+
+// Numbers of the real input's magnitude with exactly one 0 among them
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n = size.max(3);
+    let zero = rng.below(n);
+    (0..n)
+        .map(|i| {
+            if i == zero {
+                return "0\n".to_string();
+            }
+            let x: i64 = rng.range(1..=9999);
+            format!("{}\n", if rng.chance(0.5) { x } else { -x })
+        })
+        .collect()
+}
+
+crate::solution::solution!(VecDeque<(i64, usize)>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(
+                &parse(
+                    fs::read_to_string("inputs/2022/task20/example.txt")
+                        .unwrap()
+                        .trim()
+                )
+                .unwrap()
+            ),
+            3,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(
+                &parse(
+                    fs::read_to_string("inputs/2022/task20/example.txt")
+                        .unwrap()
+                        .trim()
+                )
+                .unwrap()
+            ),
+            1623178306,
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use crate::error::{ParseError, ParseResult};
+use crate::gen::Rng;
+use hashbrown::{HashMap, HashSet};
+use itertools::Either;
+use sscanf::sscanf;
+use xxcalc::calculator::Calculator;
+use xxcalc::linear_solver::LinearSolver;
+
+pub type Monkey<T> = Either<T, (String, String, u8)>;
+
+pub fn parse(inp: &str) -> ParseResult<HashMap<String, Monkey<i64>>> {
+    let monkeys: HashMap<_, _> = inp
+        .lines()
+        .map(|row| {
+            if let Ok((name, val)) = sscanf!(row, "{String}: {i64}") {
+                return Ok((name, Either::Left(val)));
+            }
+            let (name, m1, op, m2) = sscanf!(row, "{String}: {String} {str} {String}")
+                .map_err(|_| ParseError::new(row, "Invalid monkey"))?;
+            match op {
+                "+" | "-" | "*" | "/" => Ok((name, Either::Right((m1, m2, op.as_bytes()[0])))),
+                _ => Err(ParseError::new(op, "Unknown operation")),
+            }
+        })
+        .collect::<ParseResult<_>>()?;
+    for row in inp.lines() {
+        if let Some(Either::Right((m1, m2, _))) = row.split(':').next().map(|name| &monkeys[name]) {
+            if let Some(unknown) = [m1, m2].into_iter().find(|m| !monkeys.contains_key(*m)) {
+                return Err(ParseError::new(row, format!("Unknown monkey {unknown}")));
+            }
+        }
+    }
+    if !monkeys.contains_key("root") {
+        return Err(ParseError::new(inp, "No root monkey"));
+    }
+    Ok(monkeys)
+}
+
+fn solve(monkeys: &mut HashMap<String, Monkey<i64>>, target: &str) -> i64 {
+    let m = &monkeys[target].clone();
+    let res = match m {
+        Either::Left(val) => *val,
+        Either::Right((m1, m2, op)) => {
+            let m1 = solve(monkeys, m1);
+            let m2 = solve(monkeys, m2);
+            match op {
+                b'+' => m1 + m2,
+                b'-' => m1 - m2,
+                b'*' => m1 * m2,
+                b'/' => m1 / m2,
+                _ => unreachable!(),
+            }
+        }
+    };
+    monkeys.insert(target.to_string(), Either::Left(res));
+    res
+}
+
+fn solve_sym(monkeys: &mut HashMap<String, Monkey<String>>, target: &str) -> String {
+    let m = &monkeys[target].clone();
+    let res = match m {
+        Either::Left(val) => val.to_string(),
+        Either::Right((m1, m2, op)) => {
+            let m1 = solve_sym(monkeys, m1);
+            let m2 = solve_sym(monkeys, m2);
+            format!("({m1} {} {m2})", *op as char)
+        }
+    };
+    monkeys.insert(target.to_string(), Either::Left(res.clone()));
+    res
+}
+
+pub fn part1(monkeys: &HashMap<String, Monkey<i64>>) -> i64 {
+    solve(&mut monkeys.clone(), "root")
+}
+
+pub fn part2(monkeys: &HashMap<String, Monkey<i64>>) -> i64 {
+    let mut monkeys: HashMap<_, _> = monkeys
+        .clone()
+        .into_iter()
+        .map(|(k, v)| {
+            let r = if k == "humn" {
+                Either::Left("x".to_string())
+            } else {
+                match v {
+                    Either::Left(val) => Either::Left(val.to_string()),
+                    Either::Right(r) => Either::Right(r),
+                }
+            };
+            (k, r)
+        })
+        .collect();
+    let root = monkeys.get_mut("root").unwrap();
+    match root {
+        Either::Left(_) => unreachable!(),
+        Either::Right(val) => val.2 = b'=',
+    }
+    let expr = solve_sym(&mut monkeys, "root");
+    LinearSolver
+        .process(&expr)
+        .unwrap()
+        .as_f64()
+        .unwrap()
+        .round() as i64
+}
+
+fn monkey_name(rng: &mut Rng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..4).map(|_| rng.range(b'a'..=b'z') as char).collect();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+// Adds about `budget` monkeys working out `value` with exact divisions, and
+// returns the one yelling it
+fn yelling(
+    rng: &mut Rng,
+    value: i64,
+    budget: usize,
+    taken: &mut HashSet<String>,
+    rows: &mut Vec<String>,
+) -> String {
+    let name = monkey_name(rng, taken);
+    if budget == 0 || value < 2 {
+        rows.push(format!("{name}: {value}"));
+        return name;
+    }
+    let (left, op, right) = match (rng.below(4), (2..=9).find(|d| value % d == 0)) {
+        (0, _) => {
+            let left = rng.range(1..=value - 1);
+            (left, '+', value - left)
+        }
+        (1, _) => {
+            let right = rng.range(1..=20);
+            (value + right, '-', right)
+        }
+        (2, Some(divisor)) => (value / divisor, '*', divisor),
+        _ if value < 1_000_000_000 => {
+            let right = rng.range(2..=9);
+            (value * right, '/', right)
+        }
+        _ => (value - 1, '+', 1),
+    };
+    let split = rng.range(0..=budget - 1);
+    let left = yelling(rng, left, split, taken, rows);
+    let right = yelling(rng, right, budget - 1 - split, taken, rows);
+    rows.push(format!("{name}: {left} {op} {right}"));
+    name
+}
+
+// The answer to part 2 is picked first. The path from root to humn only adds,
+// subtracts and multiplies, and the other side of root matches its value.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut taken = HashSet::from(["root".to_string(), "humn".to_string()]);
+    let mut rows = vec![];
+    let steps = (size / 4).clamp(1, 30);
+    let budget = size.saturating_sub(steps + 2) / (steps + 1);
+
+    let answer: i64 = rng.range(1..=9999);
+    let (mut value, mut path) = (answer, "humn".to_string());
+    rows.push(format!("humn: {}", rng.range(1..=9999)));
+    for _ in 0..steps {
+        let operand = rng.range(2..=20);
+        let (op, next) = match rng.below(3) {
+            0 if value < 100_000_000_000 => ('*', value * operand),
+            1 if value > operand => ('-', value - operand),
+            _ => ('+', value + operand),
+        };
+        let other = yelling(rng, operand, budget, &mut taken, &mut rows);
+        let name = monkey_name(rng, &mut taken);
+        rows.push(match (op, rng.chance(0.5)) {
+            ('-', _) | (_, false) => format!("{name}: {path} {op} {other}"),
+            _ => format!("{name}: {other} {op} {path}"),
+        });
+        (value, path) = (next, name);
+    }
+    let other = yelling(rng, value, budget, &mut taken, &mut rows);
+    rows.push(if rng.chance(0.5) {
+        format!("root: {path} + {other}")
+    } else {
+        format!("root: {other} + {path}")
+    });
+    rng.shuffle(&mut rows);
+    rows.join("\n") + "\n"
+}
+
+crate::solution::solution!(HashMap<String, Monkey<i64>>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(
+                &parse(
+                    fs::read_to_string("inputs/2022/task21/example.txt")
+                        .unwrap()
+                        .trim()
+                )
+                .unwrap()
+            ),
+            152,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(
+                &parse(
+                    fs::read_to_string("inputs/2022/task21/example.txt")
+                        .unwrap()
+                        .trim()
+                )
+                .unwrap()
+            ),
+            301,
+        );
+    }
+}
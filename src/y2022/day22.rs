@@ -0,0 +1,300 @@
+use crate::error::{ParseError, ParseResult};
+use crate::frames::{self, Colour, Frame};
+use crate::gen::Rng;
+use crate::geometry::Dir;
+use crate::grid::{Grid, Pos};
+use std::fmt::{self, Display};
+
+use Dir::{Down as D, Left as L, Right as R, Up as U};
+
+// Value of the final facing in the password
+fn facing(dir: Dir) -> usize {
+    match dir {
+        R => 0,
+        D => 1,
+        L => 2,
+        U => 3,
+    }
+}
+
+// As in the puzzle, the path is drawn with the facing at every tile
+fn arrow(dir: Dir) -> char {
+    match dir {
+        R => '>',
+        D => 'v',
+        L => '<',
+        U => '^',
+    }
+}
+
+const PALETTE: &[(char, Colour)] = &[
+    ('#', [150, 150, 150]),
+    ('>', [80, 170, 255]),
+    ('v', [80, 170, 255]),
+    ('<', [80, 170, 255]),
+    ('^', [80, 170, 255]),
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Tile {
+    // Outside of the map
+    #[default]
+    Void,
+    Open,
+    Wall,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Self::Void => ' ',
+            Self::Open => '.',
+            Self::Wall => '#',
+        };
+        write!(f, "{c}")
+    }
+}
+
+pub fn parse(inp: &str) -> ParseResult<(Grid<Tile>, String)> {
+    let (map, moves) = inp
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new(inp, "Map and moves expected"))?;
+    if !map.lines().next().is_some_and(|row| row.contains('.')) {
+        return Err(ParseError::new(map, "No open tile in the first row"));
+    }
+    let moves = moves.trim();
+    if let Some((i, c)) = moves
+        .char_indices()
+        .find(|&(_, c)| !c.is_ascii_digit() && c != 'L' && c != 'R')
+    {
+        return Err(ParseError::new(&moves[i..i + c.len_utf8()], "Unknown move"));
+    }
+    let grid = Grid::parse_ragged(map, Tile::Void, |c| match c {
+        ' ' => Some(Tile::Void),
+        '.' => Some(Tile::Open),
+        '#' => Some(Tile::Wall),
+        _ => None,
+    })?;
+    if face_size(&grid).is_none() {
+        return Err(ParseError::new(
+            map,
+            "The map must fold into a cube like the example or the real input",
+        ));
+    }
+    Ok((grid, moves.to_string()))
+}
+
+// Faces of the cube in the example, as (row, column) in units of its width
+const EXAMPLE_NET: [Pos; 6] = [(0, 2), (1, 0), (1, 1), (1, 2), (2, 2), (2, 3)];
+
+// Faces of the cube in the real input
+const NET: [Pos; 6] = [(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)];
+
+// The nets that part 2 knows how to fold, by the width of their faces
+const NETS: [(usize, [Pos; 6]); 2] = [(4, EXAMPLE_NET), (50, NET)];
+
+fn face_size(map: &Grid<Tile>) -> Option<usize> {
+    let tiles: Vec<_> = map.positions().filter(|&p| map[p] != Tile::Void).collect();
+    NETS.iter()
+        .find(|(size, net)| {
+            tiles.len() == 6 * size * size
+                && tiles
+                    .iter()
+                    .all(|&(r, c)| net.contains(&(r / size, c / size)))
+        })
+        .map(|&(size, _)| size)
+}
+
+fn walk(
+    map: &Grid<Tile>,
+    moves: &str,
+    wrap: impl Fn(&Grid<Tile>, Pos, Dir) -> (Pos, Dir),
+) -> usize {
+    let mut pos = map.position(|&t| t == Tile::Open).unwrap();
+    let mut dir = R;
+    // The path so far, only drawn if someone is watching
+    let mut trail = frames::enabled().then(|| Frame::from_display(0, map, PALETTE));
+
+    let mut chars = moves.chars().peekable();
+    while let Some(chr) = chars.next() {
+        match chr {
+            'L' => dir = dir.turn_left(),
+            'R' => dir = dir.turn_right(),
+            chr => {
+                let mut steps = chr.to_digit(10).unwrap();
+                while let Some(chr) = chars.peek() {
+                    if !chr.is_ascii_digit() {
+                        break;
+                    }
+                    steps = steps * 10 + chars.next().unwrap().to_digit(10).unwrap();
+                }
+                for _ in 0..steps {
+                    let next = map.offset(pos, dir.offset());
+                    if let Some(trail) = &mut trail {
+                        trail.cells[pos] = arrow(dir);
+                    }
+                    match next.map_or(Tile::Void, |next| map[next]) {
+                        Tile::Open => pos = next.unwrap(),
+                        Tile::Wall => break,
+                        Tile::Void => {
+                            let (new_pos, new_dir) = wrap(map, pos, dir);
+                            if map[new_pos] == Tile::Wall {
+                                break;
+                            }
+                            (pos, dir) = (new_pos, new_dir);
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(trail) = &mut trail {
+            trail.cells[pos] = arrow(dir);
+            trail.step += 1;
+            frames::emit(trail.clone());
+        }
+    }
+
+    1000 * (pos.0 + 1) + 4 * (pos.1 + 1) + facing(dir)
+}
+
+pub fn part1((map, moves): &(Grid<Tile>, String)) -> usize {
+    fn wrap(map: &Grid<Tile>, mut pos: Pos, dir: Dir) -> (Pos, Dir) {
+        while let Some(prev) = map
+            .offset(pos, dir.reverse().offset())
+            .filter(|&prev| map[prev] != Tile::Void)
+        {
+            pos = prev;
+        }
+        (pos, dir)
+    }
+    walk(map, moves, wrap)
+}
+
+fn _part2<const CELL_WIDTH: usize>((map, moves): &(Grid<Tile>, String)) -> usize {
+    fn wrap<const CELL_WIDTH: usize>(_: &Grid<Tile>, (r, c): Pos, dir: Dir) -> (Pos, Dir) {
+        let cw_m1 = CELL_WIDTH - 1;
+        let (big_row, big_col, new_dir) = match CELL_WIDTH {
+            50 => match (r / CELL_WIDTH, c / CELL_WIDTH, dir) {
+                (0, 1, U) => (3, 0, R),
+                (0, 1, L) => (2, 0, R),
+                (0, 2, U) => (3, 0, U),
+                (0, 2, R) => (2, 1, L),
+                (0, 2, D) => (1, 1, L),
+                (1, 1, R) => (0, 2, U),
+                (1, 1, L) => (2, 0, D),
+                (2, 0, U) => (1, 1, R),
+                (2, 0, L) => (0, 1, R),
+                (2, 1, R) => (0, 2, L),
+                (2, 1, D) => (3, 0, L),
+                (3, 0, R) => (2, 1, U),
+                (3, 0, D) => (0, 2, D),
+                (3, 0, L) => (0, 1, D),
+                _ => unreachable!(),
+            },
+            4 => match (r / 4, c / 4, dir) {
+                (0, 2, U) => (1, 0, D),
+                (0, 2, R) => (2, 3, L),
+                (0, 2, L) => (1, 1, D),
+                (1, 0, U) => (0, 2, D),
+                (1, 0, D) => (2, 2, U),
+                (1, 0, L) => (2, 3, U),
+                (1, 1, U) => (0, 2, R),
+                (1, 1, D) => (2, 2, R),
+                (1, 2, R) => (2, 3, D),
+                (2, 2, L) => (1, 1, U),
+                (2, 2, D) => (1, 0, U),
+                (2, 3, U) => (1, 2, L),
+                (2, 3, R) => (0, 2, L),
+                (2, 3, D) => (1, 0, R),
+                _ => unreachable!(),
+            },
+            _ => unreachable!("No net of width {CELL_WIDTH}"),
+        };
+        let (offset_r, offset_c) = (r % CELL_WIDTH, c % CELL_WIDTH);
+        let i = match dir {
+            R => offset_r,
+            D => cw_m1 - offset_c,
+            L => cw_m1 - offset_r,
+            U => offset_c,
+        };
+        let (new_r, new_c) = match new_dir {
+            R => (i, 0),
+            D => (0, cw_m1 - i),
+            L => (cw_m1 - i, cw_m1),
+            U => (cw_m1, i),
+        };
+        (
+            (big_row * CELL_WIDTH + new_r, big_col * CELL_WIDTH + new_c),
+            new_dir,
+        )
+    }
+
+    walk(map, moves, wrap::<CELL_WIDTH>)
+}
+
+pub fn part2(input: &(Grid<Tile>, String)) -> usize {
+    match face_size(&input.0) {
+        Some(4) => _part2::<4>(input),
+        Some(50) => _part2::<50>(input),
+        _ => unreachable!("Only known nets are parsed"),
+    }
+}
+
+// The layout of the real input with random walls, and `size` moves
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const WIDTH: usize = 50;
+    let mut out = String::new();
+    for r in 0..4 * WIDTH {
+        let mut row = String::new();
+        for c in 0..3 * WIDTH {
+            row.push(if !NET.contains(&(r / WIDTH, c / WIDTH)) {
+                ' '
+            } else if r > 0 && rng.chance(0.1) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+        out += row.trim_end();
+        out.push('\n');
+    }
+    out.push('\n');
+    for i in 0..size.max(1) {
+        if i > 0 {
+            out.push(*rng.pick(&['L', 'R']));
+        }
+        out += &rng.range(1..=WIDTH).to_string();
+    }
+    out + "\n"
+}
+
+crate::solution::solution!((Grid<Tile>, String));
+
+#[cfg(test)]
+mod tests {
+    use super::{_part2, parse, part1};
+    use std::fs;
+
+    #[test]
+    fn rejects_unknown_nets() {
+        // A cube with faces of a single tile
+        let inp = " .\n...\n .\n .\n\n1";
+        let err = parse(inp).unwrap_err().locate(inp);
+        assert_eq!((err.line, err.column), (1, 1));
+        assert!(err.message.contains("fold into a cube"), "{err}");
+    }
+
+    #[test]
+    fn part_1_example() {
+        let inp = &fs::read_to_string("inputs/2022/task22/example.txt").unwrap();
+        let inp = inp.strip_suffix('\n').unwrap_or(inp);
+        assert_eq!(part1(&parse(inp).unwrap()), 6032,);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let inp = &fs::read_to_string("inputs/2022/task22/example.txt").unwrap();
+        let inp = inp.strip_suffix('\n').unwrap_or(inp);
+        assert_eq!(_part2::<4>(&parse(inp).unwrap()), 5031,);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::cycle::CycleFinder;
+use crate::error::ParseResult;
+use crate::frames::{self, Colour, Frame};
+use crate::gen::Rng;
+use crate::geometry::{Bounds, Dir, Point};
+use crate::grid::{Grid, Pos};
+use crate::stepper::Simulation;
+use std::fmt::{self, Display};
+
+#[derive(Clone, Debug, Default)]
+pub struct Cell {
+    occupied: bool,
+    wanted: Vec<Pos>,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if self.occupied { '#' } else { '.' })
+    }
+}
+
+pub fn parse(inp: &str) -> ParseResult<Grid<Cell>> {
+    Grid::parse(inp, |c| {
+        matches!(c, '#' | '.').then(|| Cell {
+            occupied: c == '#',
+            wanted: vec![],
+        })
+    })
+}
+
+type Directions = [Dir; 4];
+
+// North, south, west, east
+const DIRECTIONS: Directions = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+const PALETTE: &[(char, Colour)] = &[('#', [90, 200, 90])];
+
+fn show(map: &Grid<Cell>, round: usize) {
+    if frames::enabled() {
+        frames::emit(Frame::from_display(round, map, PALETTE));
+    }
+}
+
+// Returns whether any elf has moved
+fn round(map: &mut Grid<Cell>, directions: &mut Directions) -> bool {
+    // Prepare: keep an empty border around the elves
+    let (h, w) = map.size();
+    let occupied = |cell: &Cell| cell.occupied;
+    let top = map.row(0).iter().any(occupied) as usize;
+    let bottom = map.row(h - 1).iter().any(occupied) as usize;
+    let left = map.iter_col(0).any(occupied) as usize;
+    let right = map.iter_col(w - 1).any(occupied) as usize;
+    map.grow(top, bottom, left, right, Cell::default());
+    let (h, w) = map.size();
+
+    // Step 1
+    let mut wanted: Option<usize> = None;
+    for r in 1..h - 1 {
+        for c in 1..w - 1 {
+            if !map[(r, c)].occupied || map.neighbours8((r, c)).all(|p| !map[p].occupied) {
+                continue;
+            }
+            for (i, &dir) in directions.iter().enumerate() {
+                let (new_r, new_c) = map.offset((r, c), dir.offset()).unwrap();
+                if dir.is_vertical()
+                    && map.row(new_r)[c - 1..=c + 1]
+                        .iter()
+                        .all(|cell| !cell.occupied)
+                    || !dir.is_vertical()
+                        && map
+                            .iter_col(new_c)
+                            .skip(r - 1)
+                            .take(3)
+                            .all(|cell| !cell.occupied)
+                {
+                    match wanted {
+                        Some(v) => wanted = Some(v.min(i)),
+                        None => wanted = Some(i),
+                    }
+                    map[(new_r, new_c)].wanted.push((r, c));
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut has_moved = false;
+    if let Some(idx) = wanted {
+        // Step 2
+        for r in 0..h {
+            for c in 0..w {
+                let cell = &mut map[(r, c)];
+                if cell.wanted.len() == 1 {
+                    cell.occupied = true;
+                    let old = cell.wanted[0];
+                    map[old].occupied = false;
+                    has_moved = true;
+                }
+                map[(r, c)].wanted.clear();
+            }
+        }
+        directions[idx..].rotate_left(1);
+    }
+    has_moved
+}
+
+fn elves(map: &Grid<Cell>) -> impl Iterator<Item = Pos> + '_ {
+    map.positions().filter(|&p| map[p].occupied)
+}
+
+fn bounds(map: &Grid<Cell>) -> Bounds<Point> {
+    Bounds::from_points(elves(map).map(Point::from)).expect("No elves")
+}
+
+fn empty_ground(map: &Grid<Cell>) -> usize {
+    let size = bounds(map).size();
+    (size.x * size.y) as usize - elves(map).count()
+}
+
+// Elves within their bounding box as a bitmap, and the order of directions
+fn state(map: &Grid<Cell>, directions: &Directions) -> (isize, Vec<u64>, Directions) {
+    let bounds = bounds(map);
+    let size = bounds.size();
+    let mut bits = vec![0u64; ((size.x * size.y) as usize).div_ceil(64)];
+    for elf in elves(map) {
+        let p = Point::from(elf) - bounds.min;
+        let i = (p.y * size.x + p.x) as usize;
+        bits[i / 64] |= 1 << (i % 64);
+    }
+    (size.x, bits, *directions)
+}
+
+// Empty ground tiles after any number of rounds
+pub fn empty_after(map: &Grid<Cell>, rounds: usize) -> usize {
+    let mut map = map.clone();
+    let mut directions = DIRECTIONS;
+    let mut finder = CycleFinder::new();
+    for i in 0..rounds {
+        let empty = empty_ground(&map) as i64;
+        if finder.push(state(&map, &directions), empty).is_some() {
+            return finder.extrapolate(rounds).unwrap() as usize;
+        }
+        round(&mut map, &mut directions);
+        show(&map, i + 1);
+    }
+    empty_ground(&map)
+}
+
+pub fn part1(map: &Grid<Cell>) -> usize {
+    empty_after(map, 10)
+}
+
+// The first round after which the elves are back in a state seen before,
+// which means nobody has moved
+pub fn part2(map: &Grid<Cell>) -> usize {
+    let mut map = map.clone();
+    let mut directions = DIRECTIONS;
+    let mut finder = CycleFinder::new();
+    let mut rounds = 0;
+    loop {
+        if let Some(cycle) = finder.push(state(&map, &directions), ()) {
+            break cycle.start + 1;
+        }
+        round(&mut map, &mut directions);
+        rounds += 1;
+        show(&map, rounds);
+    }
+}
+
+// The elves spreading out round by round, until nobody moves
+#[derive(Clone)]
+struct Spread {
+    map: Grid<Cell>,
+    directions: Directions,
+    rounds: usize,
+    settled: bool,
+}
+
+impl Simulation for Spread {
+    fn step(&mut self) -> bool {
+        if self.settled {
+            return false;
+        }
+        self.settled = !round(&mut self.map, &mut self.directions);
+        self.rounds += 1;
+        true
+    }
+    fn frame(&self) -> Frame {
+        Frame::from_display(self.rounds, &self.map, PALETTE)
+    }
+    fn snapshot(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+pub fn simulation(inp: &str) -> ParseResult<Box<dyn Simulation>> {
+    Ok(Box::new(Spread {
+        map: parse(inp)?,
+        directions: DIRECTIONS,
+        rounds: 0,
+        settled: false,
+    }))
+}
+
+// A square patch of `size` tiles on a side, about a third of them elves
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    let mut map = Grid::from_vec(
+        (0..side * side)
+            .map(|_| if rng.chance(0.3) { '#' } else { '.' })
+            .collect(),
+        side,
+    );
+    map[(side / 2, side / 2)] = '#';
+    map.to_string() + "\n"
+}
+
+crate::solution::solution!(Grid<Cell>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        let inp = &fs::read_to_string("inputs/2022/task23/example.txt").unwrap();
+        let inp = inp.strip_suffix('\n').unwrap_or(inp);
+        assert_eq!(part1(&parse(inp).unwrap()), 110);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let inp = &fs::read_to_string("inputs/2022/task23/example.txt").unwrap();
+        let inp = inp.strip_suffix('\n').unwrap_or(inp);
+        assert_eq!(part2(&parse(inp).unwrap()), 20);
+    }
+}
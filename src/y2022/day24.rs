@@ -0,0 +1,369 @@
+use crate::error::{ParseError, ParseResult};
+use crate::frames::{self, Colour, Frame};
+use crate::gen::Rng;
+use crate::geometry::{Dir, Point};
+use crate::grid::Grid;
+use crate::search;
+use crate::stepper::Simulation;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use Dir::{Down, Left, Right, Up};
+
+pub fn parse(inp: &str) -> ParseResult<Grid<Vec<Dir>>> {
+    let data: Vec<_> = inp.lines().collect();
+    if data.len() < 3 {
+        return Err(ParseError::new(
+            inp,
+            "The valley must have walls and at least one row",
+        ));
+    }
+    let len = data[0].chars().count().saturating_sub(2);
+    let mut cells = vec![];
+    for row in &data[1..data.len() - 1] {
+        if row.chars().count() != len + 2 || !row.starts_with('#') || !row.ends_with('#') {
+            return Err(ParseError::new(row, "Rows must be enclosed by walls"));
+        }
+        for (i, c) in row.char_indices().skip(1).take(len) {
+            cells.push(match c {
+                '>' => vec![Right],
+                'v' => vec![Down],
+                '<' => vec![Left],
+                '^' => vec![Up],
+                '.' => vec![],
+                _ => return Err(ParseError::new(&row[i..i + c.len_utf8()], "Unknown tile")),
+            });
+        }
+    }
+    Ok(Grid::from_vec(cells, len))
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// Blizzards wrap around, so one is at the cell at time `t` if it started `t`
+// cells away from it, facing it
+fn blizzards(
+    map: &Grid<Vec<Dir>>,
+    (r, c): (usize, usize),
+    t: usize,
+) -> impl Iterator<Item = Dir> + '_ {
+    let (h, w) = map.size();
+    [
+        ((r, (c + w - t % w) % w), Right),
+        ((r, (c + t) % w), Left),
+        (((r + h - t % h) % h, c), Down),
+        (((r + t) % h, c), Up),
+    ]
+    .into_iter()
+    .filter(move |(pos, dir)| map[*pos].contains(dir))
+    .map(|(_, dir)| dir)
+}
+
+fn is_free(map: &Grid<Vec<Dir>>, pos: (usize, usize), t: usize) -> bool {
+    blizzards(map, pos, t).next().is_none()
+}
+
+// Where the expedition can be at minute `t`, coming from `pos`
+fn moves(
+    map: &Grid<Vec<Dir>>,
+    start: Point,
+    end: Point,
+    pos: Point,
+    t: usize,
+) -> impl Iterator<Item = Point> + '_ {
+    Dir::ALL
+        .iter()
+        .map(move |dir| pos + dir.offset())
+        .chain([pos])
+        .filter(move |&next| {
+            // The start is outside of the border, we may wait there
+            next == start
+                || next == end
+                || next
+                    .to_pos()
+                    .is_some_and(|p| map.get(p).is_some() && is_free(map, p, t))
+        })
+}
+
+const PALETTE: &[(char, Colour)] = &[
+    ('E', [90, 230, 90]),
+    ('#', [150, 150, 150]),
+    ('>', [120, 180, 255]),
+    ('v', [120, 180, 255]),
+    ('<', [120, 180, 255]),
+    ('^', [120, 180, 255]),
+];
+
+// The valley at minute `t` as in the puzzle, with every place the expedition
+// could be at as `E`
+fn picture(map: &Grid<Vec<Dir>>, frontier: &HashSet<Point>, t: usize) -> Grid<char> {
+    let (h, w) = map.size();
+    let mut cells = Grid::new(h + 2, w + 2);
+    for (r, c) in cells.positions() {
+        let p = Point::new(c as isize - 1, r as isize - 1);
+        cells[(r, c)] = match p.to_pos().filter(|&pos| map.get(pos).is_some()) {
+            _ if frontier.contains(&p) => 'E',
+            Some(pos) => {
+                let here: Vec<_> = blizzards(map, pos, t).collect();
+                match here[..] {
+                    [] => '.',
+                    [Right] => '>',
+                    [Down] => 'v',
+                    [Left] => '<',
+                    [Up] => '^',
+                    _ => char::from_digit(here.len() as u32, 10).unwrap(),
+                }
+            }
+            None if p == Point::new(0, -1) || p == Point::new(w as isize - 1, h as isize) => '.',
+            None => '#',
+        };
+    }
+    cells
+}
+
+// Every place the expedition could be at, minute by minute, on its way to
+// each of the goals in turn
+#[derive(Clone)]
+struct Expedition {
+    map: Rc<Grid<Vec<Dir>>>,
+    frontier: HashSet<Point>,
+    goals: Vec<Point>,
+    t: usize,
+}
+
+impl Expedition {
+    fn new(map: Rc<Grid<Vec<Dir>>>, start: Point, goals: Vec<Point>, t: usize) -> Self {
+        Self {
+            map,
+            frontier: HashSet::from([start]),
+            goals,
+            t,
+        }
+    }
+}
+
+impl Simulation for Expedition {
+    fn step(&mut self) -> bool {
+        let Some(&goal) = self.goals.first() else {
+            return false;
+        };
+        if self.frontier.is_empty() {
+            return false;
+        }
+        let (h, w) = self.map.size();
+        let (start, end) = (Point::new(0, -1), Point::new(w as isize - 1, h as isize));
+        self.t += 1;
+        self.frontier = self
+            .frontier
+            .iter()
+            .flat_map(|&pos| moves(&self.map, start, end, pos, self.t))
+            .collect();
+        if self.frontier.contains(&goal) {
+            self.frontier = HashSet::from([goal]);
+            self.goals.remove(0);
+        }
+        true
+    }
+    fn frame(&self) -> Frame {
+        Frame::new(self.t, picture(&self.map, &self.frontier, self.t), PALETTE)
+    }
+    fn snapshot(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+// There, back and there again, as in part 2
+pub fn simulation(inp: &str) -> ParseResult<Box<dyn Simulation>> {
+    let map = parse(inp)?;
+    let (h, w) = map.size();
+    let (start, end) = (Point::new(0, -1), Point::new(w as isize - 1, h as isize));
+    Ok(Box::new(Expedition::new(
+        Rc::new(map),
+        start,
+        vec![end, start, end],
+        0,
+    )))
+}
+
+// Every minute of a trip that takes `minutes`, while someone is watching
+fn show_trip(map: &Grid<Vec<Dir>>, start: Point, end: Point, t0: usize, minutes: usize) {
+    if !frames::enabled() {
+        return;
+    }
+    let mut trip = Expedition::new(Rc::new(map.clone()), start, vec![end], t0);
+    frames::emit(trip.frame());
+    for _ in 0..minutes {
+        trip.step();
+        frames::emit(trip.frame());
+    }
+}
+
+// Minutes to get from `start` to `end`, leaving at minute `t0`
+fn solve(map: &Grid<Vec<Dir>>, start: Point, end: Point, t0: usize) -> Option<usize> {
+    let (h, w) = map.size();
+    // Blizzards are back to their initial positions after this many minutes
+    let period = h * w / gcd(h, w);
+    let path = search::bfs(
+        (start, t0 % period),
+        |&(pos, t)| {
+            let t = (t + 1) % period;
+            moves(map, start, end, pos, t).map(move |next| (next, t))
+        },
+        |&(pos, _)| pos == end,
+    );
+    // Nothing to show when there is no way through
+    let minutes = path?.cost;
+    show_trip(map, start, end, t0, minutes);
+    Some(minutes)
+}
+
+// Minutes of the trips there, back and there again
+fn trips(map: &Grid<Vec<Dir>>) -> Option<[usize; 3]> {
+    let (h, w) = map.size();
+    let (start, end) = (Point::new(0, -1), Point::new(w as isize - 1, h as isize));
+    let first = solve(map, start, end, 0)?;
+    let second = solve(map, end, start, first)?;
+    let third = solve(map, start, end, first + second)?;
+    Some([first, second, third])
+}
+
+pub fn part1(map: &Grid<Vec<Dir>>) -> usize {
+    let (h, w) = map.size();
+    solve(
+        map,
+        Point::new(0, -1),
+        Point::new(w as isize - 1, h as isize),
+        0,
+    )
+    .expect("Should be solvable!")
+}
+
+pub fn part2(map: &Grid<Vec<Dir>>) -> usize {
+    trips(map).expect("Should be solvable!").iter().sum()
+}
+
+// Minutes and places of the expedition in each row or column
+type Visits = Vec<Vec<(usize, usize)>>;
+
+// Where the expedition is at every minute of the three trips when it goes
+// down the first column and along the last row without waiting, by row and
+// by column. That is as fast as it gets, so every trip takes `h + w` minutes.
+fn route(h: usize, w: usize) -> (Visits, Visits) {
+    let there: Vec<_> = (0..h)
+        .map(|r| (r, 0))
+        .chain((1..w).map(|c| (h - 1, c)))
+        .collect();
+    let trip = h + w;
+    let (mut rows, mut cols) = (vec![vec![]; h], vec![vec![]; w]);
+    for (i, &(r, c)) in there.iter().enumerate() {
+        // There, back and there again
+        for t in [i + 1, 2 * trip - i - 1, 2 * trip + i + 1] {
+            rows[r].push((t, c));
+            cols[c].push((t, r));
+        }
+    }
+    (rows, cols)
+}
+
+// A valley `size` tiles wide with a blizzard on about every fourth tile. No
+// blizzard moves through the entrance or the exit, and none is ever where the
+// expedition is on its `route`, so every valley has a way through.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (h, w) = ((size / 4).max(2), size.max(3));
+    let (rows, cols) = route(h, w);
+    let hits = |(r, c): (usize, usize), dir: Dir| match dir {
+        Right => rows[r].iter().any(|&(t, at)| (c + t) % w == at),
+        Left => rows[r].iter().any(|&(t, at)| (c + w - t % w) % w == at),
+        Down => cols[c].iter().any(|&(t, at)| (r + t) % h == at),
+        Up => cols[c].iter().any(|&(t, at)| (r + h - t % h) % h == at),
+    };
+    let cells = (0..h * w)
+        .map(|i| {
+            let ends = i % w == 0 || i % w == w - 1;
+            let dirs: &[Dir] = if ends { &[Left, Right] } else { &Dir::ALL };
+            let dir = *rng.pick(dirs);
+            if rng.chance(0.25) && !hits((i / w, i % w), dir) {
+                vec![dir]
+            } else {
+                vec![]
+            }
+        })
+        .collect();
+    let map = Grid::from_vec(cells, w);
+    let mut out = format!("#.{}\n", "#".repeat(w));
+    for r in 0..h {
+        let row: String = map
+            .row(r)
+            .iter()
+            .map(|cell| match cell.first() {
+                Some(Right) => '>',
+                Some(Down) => 'v',
+                Some(Left) => '<',
+                Some(Up) => '^',
+                None => '.',
+            })
+            .collect();
+        out += &format!("#{row}#\n");
+    }
+    out + &format!("{}.#\n", "#".repeat(w))
+}
+
+crate::solution::solution!(Grid<Vec<Dir>>);
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, parse, part1, part2, trips};
+    use crate::frames::{capture, Ascii};
+    use crate::gen::Rng;
+    use std::{fs, io};
+
+    #[test]
+    fn generated_valleys_have_a_way_through() {
+        let (mut blizzards, mut tiles) = (0, 0);
+        for (seed, size) in (0..20).zip(3..) {
+            let map = parse(&generate(&mut Rng::new(seed), size)).unwrap();
+            let (h, w) = map.size();
+            assert_eq!(trips(&map), Some([h + w; 3]), "seed {seed}");
+            blizzards += map.iter().filter(|cell| !cell.is_empty()).count();
+            tiles += h * w;
+        }
+        // Small valleys lose the blizzards that would cross the route
+        assert!(blizzards * 10 > tiles, "{blizzards} of {tiles}");
+    }
+
+    #[test]
+    fn shows_no_trip_through_blocked_valleys() {
+        // The blizzards fill the only row at every minute
+        let map = parse("#.####\n#>>>>#\n####.#").unwrap();
+        let (answer, written) = capture(Box::new(Ascii::new(io::sink())), || trips(&map));
+        assert_eq!(answer, None);
+        assert_eq!(written.unwrap(), 0);
+    }
+
+    #[test]
+    fn rejects_unknown_tiles() {
+        let inp = "#.##\n#é.#\n##.#";
+        let err = parse(inp).unwrap_err().locate(inp);
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "é"));
+    }
+
+    #[test]
+    fn part_1_example() {
+        let inp = &fs::read_to_string("inputs/2022/task24/example.txt").unwrap();
+        let inp = inp.strip_suffix('\n').unwrap_or(inp);
+        assert_eq!(part1(&parse(inp).unwrap()), 18);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let inp = &fs::read_to_string("inputs/2022/task24/example.txt").unwrap();
+        let inp = inp.strip_suffix('\n').unwrap_or(inp);
+        assert_eq!(part2(&parse(inp).unwrap()), 54);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::error::{ParseError, ParseResult};
+use crate::gen::Rng;
 use itertools::Itertools;
 use std::{
     fmt::{self, Display},
@@ -7,23 +9,26 @@ use std::{
 const BASE: i64 = 5;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
-struct Snafu {
+pub struct Snafu {
     values: Vec<i64>,
 }
 
 impl FromStr for Snafu {
-    type Err = String;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let values = s
-            .bytes()
+            .char_indices()
             .rev()
-            .map(|digit| match digit {
-                b'2' => Ok(2),
-                b'1' => Ok(1),
-                b'0' => Ok(0),
-                b'-' => Ok(-1),
-                b'=' => Ok(-2),
-                _ => Err("Unknown digit".to_string()),
+            .map(|(i, digit)| match digit {
+                '2' => Ok(2),
+                '1' => Ok(1),
+                '0' => Ok(0),
+                '-' => Ok(-1),
+                '=' => Ok(-2),
+                _ => Err(ParseError::new(
+                    &s[i..i + digit.len_utf8()],
+                    "Unknown digit",
+                )),
             })
             .collect::<Result<Vec<_>, _>>()?;
         Ok(Self { values })
@@ -79,30 +84,41 @@ impl From<i64> for Snafu {
     }
 }
 
-pub fn prob1(inp: &str) -> String {
-    Snafu::from(
-        inp.lines()
-            .map(Snafu::from_str)
-            .map(Result::unwrap)
-            .map(i64::from)
-            .sum::<i64>(),
-    )
-    .to_string()
+pub fn parse(inp: &str) -> ParseResult<Vec<Snafu>> {
+    inp.lines().map(Snafu::from_str).collect()
 }
 
-pub fn prob2(_inp: &str) -> &str {
-    panic!("No part 2 today:(")
+pub fn part1(numbers: &[Snafu]) -> String {
+    Snafu::from(numbers.iter().cloned().map(i64::from).sum::<i64>()).to_string()
 }
 
+pub fn part2(_: &[Snafu]) -> &'static str {
+    "No part 2 today:("
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let mut number = String::from(*rng.pick(&["1", "2"]));
+            for _ in 0..rng.below(15) {
+                number.push(*rng.pick(&['2', '1', '0', '-', '=']));
+            }
+            number + "\n"
+        })
+        .collect()
+}
+
+crate::solution::solution!(Vec<Snafu>);
+
 #[cfg(test)]
 mod tests {
-    use super::prob1;
+    use super::{parse, part1};
     use std::fs;
 
     #[test]
     fn part_1_example() {
-        let inp = &fs::read_to_string("inputs/task25/example.txt").unwrap();
-        let inp = inp.strip_suffix('\n').unwrap_or(&inp);
-        assert_eq!(prob1(inp), "2=-1=0");
+        let inp = &fs::read_to_string("inputs/2022/task25/example.txt").unwrap();
+        let inp = inp.strip_suffix('\n').unwrap_or(inp);
+        assert_eq!(part1(&parse(inp).unwrap()), "2=-1=0");
     }
 }
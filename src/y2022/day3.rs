@@ -0,0 +1,113 @@
+use crate::error::{ParseError, ParseResult};
+use crate::gen::Rng;
+use std::collections::HashSet;
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn score(c: char) -> usize {
+    match ALPHABET.chars().position(|a| a == c) {
+        Some(x) => x + 1,
+        None => panic!("Unknown letter"),
+    }
+}
+
+pub fn parse(inp: &str) -> ParseResult<Vec<String>> {
+    inp.lines()
+        .map(
+            |row| match row.char_indices().find(|&(_, c)| !ALPHABET.contains(c)) {
+                Some((i, c)) => Err(ParseError::new(&row[i..i + c.len_utf8()], "Unknown item")),
+                None => Ok(row.to_string()),
+            },
+        )
+        .collect()
+}
+
+pub fn part1(rucksacks: &[String]) -> usize {
+    let mut ans: usize = 0;
+    for inp in rucksacks {
+        let half_length = inp.len() / 2;
+        let s1 = &inp[..half_length];
+        let s2: HashSet<char> = inp[half_length..].chars().collect();
+        let rpt = match s1.chars().find(|c| s2.contains(c)) {
+            Some(x) => x,
+            None => panic!("Repeated char not found in {inp}."),
+        };
+        ans += score(rpt);
+    }
+    ans
+}
+
+pub fn part2(rucksacks: &[String]) -> usize {
+    let mut ans: usize = 0;
+
+    for group in rucksacks.chunks(3) {
+        let [inp1, inp2, inp3] = group else {
+            panic!("Rucksacks must come in groups of three.");
+        };
+        let s2: HashSet<char> = inp2.chars().collect();
+        let s3: HashSet<char> = inp3.chars().collect();
+        let rpt = match inp1.chars().find(|c| s2.contains(c) && s3.contains(c)) {
+            Some(x) => x,
+            None => panic!("Repeated char not found in {inp1} {inp2} {inp3}."),
+        };
+        ans += score(rpt);
+    }
+    ans
+}
+
+// Groups of three rucksacks. Each one gets its own letters besides the badge,
+// so the shared item and the badge are the only repeats.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.div_ceil(3).max(1) {
+        let mut letters: Vec<char> = ALPHABET.chars().collect();
+        rng.shuffle(&mut letters);
+        let badge = letters.pop().unwrap();
+        for pool in letters.chunks(letters.len() / 3) {
+            let (shared, halves) = (pool[0], &pool[1..]);
+            let (left, right) = halves.split_at(halves.len() / 2);
+            let len = rng.range(2..=16);
+            let mut half = |items: &[char]| -> Vec<char> {
+                let mut half: Vec<char> = (1..len).map(|_| *rng.pick(items)).collect();
+                half.push(shared);
+                half
+            };
+            let (mut left, mut right) = (half(left), half(right));
+            let with_badge = if rng.chance(0.5) {
+                &mut left
+            } else {
+                &mut right
+            };
+            with_badge[0] = badge;
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            out.extend(left.into_iter().chain(right));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+crate::solution::solution!(Vec<String>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task3/example.txt").unwrap()).unwrap()),
+            157,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task3/example.txt").unwrap()).unwrap()),
+            70,
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error::{field, ParseError, ParseResult};
+use crate::gen::Rng;
+
+type Pair = ((u32, u32), (u32, u32));
+
+fn solve(pairs: &[Pair], validator: &mut dyn FnMut((u32, u32), (u32, u32)) -> bool) -> u32 {
+    let mut ans: u32 = 0;
+    for &(a, b) in pairs {
+        if validator(a, b) {
+            ans += 1;
+        }
+    }
+    ans
+}
+
+fn range(text: &str) -> ParseResult<(u32, u32)> {
+    let (b, e) = text
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(text, "Expected a range"))?;
+    Ok((field(b, "section")?, field(e, "section")?))
+}
+
+pub fn parse(inp: &str) -> ParseResult<Vec<Pair>> {
+    inp.lines()
+        .map(|row| {
+            let (a, b) = row
+                .split_once(',')
+                .ok_or_else(|| ParseError::new(row, "Expected a pair of ranges"))?;
+            Ok((range(a)?, range(b)?))
+        })
+        .collect()
+}
+
+pub fn part1(pairs: &[Pair]) -> u32 {
+    fn validator(a: (u32, u32), b: (u32, u32)) -> bool {
+        (a.0 >= b.0 && a.1 <= b.1) || (a.0 <= b.0 && a.1 >= b.1)
+    }
+    solve(pairs, &mut validator)
+}
+
+pub fn part2(pairs: &[Pair]) -> u32 {
+    fn validator(a: (u32, u32), b: (u32, u32)) -> bool {
+        (a.0 <= b.0 && b.0 <= a.1)
+            || (a.0 <= b.1 && b.1 <= a.1)
+            || (a.0 >= b.0 && a.1 <= b.1)
+            || (a.0 <= b.0 && a.1 >= b.1)
+    }
+    solve(pairs, &mut validator)
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let (a, b) = (rng.range(1..=99), rng.range(1..=99));
+        format!("{}-{}", a.min(b), a.max(b))
+    };
+    (0..size)
+        .map(|_| format!("{},{}\n", range(), range()))
+        .collect()
+}
+
+crate::solution::solution!(Vec<Pair>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task4/example.txt").unwrap()).unwrap()),
+            2,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task4/example.txt").unwrap()).unwrap()),
+            4,
+        );
+    }
+}
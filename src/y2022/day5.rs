@@ -0,0 +1,151 @@
+use crate::error::{ParseError, ParseResult};
+use crate::gen::Rng;
+use regex::Regex;
+use sscanf::sscanf;
+use std::vec::Vec;
+
+type Mutator = dyn FnMut(&mut [Vec<u8>], usize, usize, usize);
+type Move = (usize, usize, usize);
+
+pub fn parse(inp: &str) -> ParseResult<(Vec<Vec<u8>>, Vec<Move>)> {
+    let mut stacks: Vec<Vec<u8>> = Vec::new();
+    let re = Regex::new(r"(\[(?P<ch>\w)\]| (?P<empty> )  ?)").unwrap();
+    let (header, moves) = inp
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new(inp, "Header and moves expected"))?;
+
+    // First part: header
+    for inp in header.lines() {
+        // Ignore numbering 1..n
+        if !inp.chars().nth(2).is_some_and(|c| c.is_numeric()) {
+            for (i, ch) in re.captures_iter(inp).enumerate() {
+                if stacks.get(i).is_none() {
+                    stacks.push(Vec::new());
+                }
+                if ch.name("ch").is_some() {
+                    stacks[i]
+                        .push(ch["ch"].chars().next().expect("Non-empty string expected") as u8);
+                }
+            }
+        }
+    }
+
+    // We were reading top-to-bottom, reverse
+    stacks.iter_mut().for_each(|x| x.reverse());
+
+    let moves = moves
+        .lines()
+        .map(|inp| {
+            let (count, from, to) =
+                sscanf!(inp, "move {usize} from {usize} to {usize}").map_err(|_| {
+                    ParseError::new(inp, "Expected \"move <n> from <stack> to <stack>\"")
+                })?;
+            if !(1..=stacks.len()).contains(&from) || !(1..=stacks.len()).contains(&to) {
+                return Err(ParseError::new(inp, "Unknown stack"));
+            }
+            Ok((count, from - 1, to - 1))
+        })
+        .collect::<ParseResult<_>>()?;
+    Ok((stacks, moves))
+}
+
+fn solve((stacks, moves): &(Vec<Vec<u8>>, Vec<Move>), mutate: &mut Mutator) -> Vec<Vec<u8>> {
+    let mut stacks = stacks.clone();
+    for &(count, from, to) in moves {
+        mutate(&mut stacks, from, to, count);
+    }
+    stacks
+}
+
+fn display(stacks: Vec<Vec<u8>>) -> String {
+    stacks
+        .iter()
+        .map(|s| *s.last().expect("Can't be empty") as char)
+        .collect()
+}
+
+pub fn part1(input: &(Vec<Vec<u8>>, Vec<Move>)) -> String {
+    fn mutate(stacks: &mut [Vec<u8>], from: usize, to: usize, count: usize) {
+        for _ in 0..count {
+            let tmp = stacks[from].pop().expect("Not enough items");
+            stacks[to].push(tmp);
+        }
+    }
+    display(solve(input, &mut mutate))
+}
+
+pub fn part2(input: &(Vec<Vec<u8>>, Vec<Move>)) -> String {
+    fn mutate(stacks: &mut [Vec<u8>], from: usize, to: usize, count: usize) {
+        let idx = stacks[from].len() - count;
+        let tmp: Vec<u8> = stacks[from].drain(idx..).collect();
+        stacks[to].extend(tmp);
+    }
+    display(solve(input, &mut mutate))
+}
+
+// Moves never empty a stack, so every stack has a top crate at the end
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n = rng.range(2..=9);
+    let mut stacks: Vec<Vec<u8>> = vec![vec![]; n];
+    for i in 0..rng.range(n + 1..=n * 6) {
+        let stack = if i < n { i } else { rng.below(n) };
+        stacks[stack].push(rng.range(b'A'..=b'Z'));
+    }
+
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    let mut out = String::new();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|s| match s.get(level) {
+                Some(&c) => format!("[{}]", c as char),
+                None => "   ".to_string(),
+            })
+            .collect();
+        out += row.join(" ").trim_end();
+        out.push('\n');
+    }
+    let numbers: Vec<String> = (1..=n).map(|i| format!(" {i} ")).collect();
+    out += numbers.join(" ").trim_end();
+    out += "\n\n";
+
+    let mut lens: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    for _ in 0..size {
+        let from = loop {
+            let from = rng.below(n);
+            if lens[from] > 1 {
+                break from;
+            }
+        };
+        let to = (from + rng.range(1..=n - 1)) % n;
+        let count = rng.range(1..=lens[from] - 1);
+        lens[from] -= count;
+        lens[to] += count;
+        out += &format!("move {count} from {} to {}\n", from + 1, to + 1);
+    }
+    out
+}
+
+crate::solution::solution!((Vec<Vec<u8>>, Vec<Move>));
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task5/example.txt").unwrap()).unwrap()),
+            "CMZ",
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task5/example.txt").unwrap()).unwrap()),
+            "MCD",
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error::{ParseError, ParseResult};
+use crate::gen::Rng;
+use std::collections::{HashSet, VecDeque};
+
+fn all_distinct(collection: &VecDeque<u8>) -> bool {
+    let mut unique = HashSet::new();
+    collection.iter().all(move |x| unique.insert(x))
+}
+
+fn solve(inp: &str, seq_length: usize) -> usize {
+    let mut curr: VecDeque<u8> = VecDeque::with_capacity(seq_length);
+    let mut first_idx: usize = seq_length;
+    for (i, x) in inp.chars().enumerate() {
+        if i >= seq_length {
+            let old = curr.pop_front().unwrap();
+            let x = x as u8;
+            curr.push_back(x);
+            if x != old && all_distinct(&curr) {
+                first_idx = i + 1;
+                break;
+            }
+        } else {
+            curr.push_back(x as u8);
+        }
+    }
+    first_idx
+}
+
+pub fn parse(inp: &str) -> ParseResult<String> {
+    let signal = inp.trim();
+    match signal.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+        Some((i, c)) => Err(ParseError::new(
+            &signal[i..i + c.len_utf8()],
+            "Unexpected character",
+        )),
+        None => Ok(signal.to_string()),
+    }
+}
+
+pub fn part1(signal: &str) -> usize {
+    solve(signal, 4)
+}
+
+pub fn part2(signal: &str) -> usize {
+    solve(signal, 14)
+}
+
+// Few distinct letters first, so both markers come late in the signal
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    rng.shuffle(&mut letters);
+    let mut signal: Vec<u8> = (0..size / 2).map(|_| *rng.pick(&letters[..3])).collect();
+    signal.extend((0..size / 2).map(|_| *rng.pick(&letters[..13])));
+    let mut marker = letters[..14].to_vec();
+    rng.shuffle(&mut marker);
+    signal.extend(marker);
+    signal.extend((0..size).map(|_| *rng.pick(&letters)));
+    String::from_utf8(signal).unwrap() + "\n"
+}
+
+crate::solution::solution!(String);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task6/example.txt").unwrap()).unwrap()),
+            11,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task6/example.txt").unwrap()).unwrap()),
+            26,
+        );
+    }
+}
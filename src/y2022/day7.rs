@@ -0,0 +1,187 @@
+use crate::error::{field, ParseError, ParseResult};
+use crate::gen::Rng;
+use crate::trace;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub enum Command {
+    LS,
+    CD(String),
+}
+
+impl Command {
+    fn parse(line: &str) -> ParseResult<Command> {
+        let mut words_iter = line.split_whitespace().skip(1);
+        match (words_iter.next(), words_iter.next()) {
+            (Some("ls"), None) => Ok(Command::LS),
+            (Some("cd"), Some(dir)) => Ok(Command::CD(dir.to_string())),
+            _ => Err(ParseError::new(line, "Unknown command")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Out {
+    File(u32),
+    Dir,
+}
+
+impl Out {
+    fn parse(line: &str) -> ParseResult<Out> {
+        match line.split_whitespace().next() {
+            Some("dir") => Ok(Out::Dir),
+            Some(file_size) => Ok(Out::File(field(file_size, "file size")?)),
+            None => Err(ParseError::new(line, "Empty line")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Line {
+    Command(Command),
+    Out(Out),
+}
+
+pub fn parse(inp: &str) -> ParseResult<Vec<Line>> {
+    inp.lines()
+        .map(|row| {
+            Ok(if row.starts_with('$') {
+                Line::Command(Command::parse(row)?)
+            } else {
+                Line::Out(Out::parse(row)?)
+            })
+        })
+        .collect()
+}
+
+fn relax(stack: &[String], dir_sizes: &mut HashMap<String, u32>, curr_size: u32) {
+    let mut path = "".to_string();
+    for fld in stack.iter() {
+        if path.len() > 1 {
+            // Not "" or "/"
+            path.push('/');
+        }
+        path.push_str(fld);
+        if !dir_sizes.contains_key(&path) {
+            dir_sizes.insert(path.clone(), curr_size);
+        } else {
+            *dir_sizes.get_mut(&path).unwrap() += curr_size;
+        }
+    }
+}
+
+fn solve(lines: &[Line]) -> HashMap<String, u32> {
+    let mut dir_sizes: HashMap<String, u32> = HashMap::new();
+    let mut stack: Vec<String> = Vec::new();
+    let mut curr_size: u32 = 0;
+
+    for line in lines {
+        match line {
+            Line::Command(Command::CD(dir)) => {
+                relax(&stack, &mut dir_sizes, curr_size);
+                curr_size = 0;
+                if dir == ".." {
+                    stack.pop();
+                } else {
+                    stack.push(dir.clone());
+                }
+            }
+            Line::Command(Command::LS) => {}
+            Line::Out(Out::Dir) => {}
+            Line::Out(Out::File(size)) => {
+                curr_size += size;
+            }
+        }
+    }
+
+    relax(&stack, &mut dir_sizes, curr_size);
+    dir_sizes
+}
+
+pub fn part1(lines: &[Line]) -> u32 {
+    let dir_sizes = solve(lines);
+    for (path, &size) in &dir_sizes {
+        trace::event!(Debug, "directory", path = path.as_str(), size = size);
+    }
+    dir_sizes.values().filter(|&&v| v < 100000).sum()
+}
+
+pub fn part2(lines: &[Line]) -> u32 {
+    let dir_sizes = solve(lines);
+    let to_clean = 30_000_000 + dir_sizes["/"] - 70_000_000;
+    *dir_sizes.values().filter(|&&v| v > to_clean).min().unwrap()
+}
+
+// A random tree of `size` directories. The disk is between 40M and 70M full,
+// so there is always a directory worth deleting.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n = size.max(1);
+    let mut taken = HashSet::new();
+    let mut name = |rng: &mut Rng| loop {
+        let name: String = (0..rng.range(1..=8))
+            .map(|_| rng.range(b'a'..=b'z') as char)
+            .collect();
+        if taken.insert(name.clone()) {
+            break name;
+        }
+    };
+    let names: Vec<String> = (0..n).map(|_| name(rng)).collect();
+    let mut subdirs: Vec<Vec<usize>> = vec![vec![]; n];
+    for dir in 1..n {
+        subdirs[rng.below(dir)].push(dir);
+    }
+    let files: Vec<usize> = (0..n)
+        .map(|dir| rng.range(usize::from(dir == 0)..=4))
+        .collect();
+    let weights: Vec<u64> = (0..files.iter().sum())
+        .map(|_| rng.range(1..=1000))
+        .collect();
+    let (total, weight) = (
+        rng.range(41_000_000..=69_000_000),
+        weights.iter().sum::<u64>(),
+    );
+    let mut sizes = weights.iter().map(|w| (w * total / weight).max(1));
+
+    // `Some` enters a directory and `None` goes back up
+    let mut out = String::new();
+    let mut stack = vec![Some(0)];
+    while let Some(step) = stack.pop() {
+        let Some(dir) = step else {
+            out += "$ cd ..\n";
+            continue;
+        };
+        out += &format!("$ cd {}\n$ ls\n", if dir == 0 { "/" } else { &names[dir] });
+        for &sub in &subdirs[dir] {
+            out += &format!("dir {}\n", names[sub]);
+            stack.extend([None, Some(sub)]);
+        }
+        for _ in 0..files[dir] {
+            out += &format!("{} {}\n", sizes.next().unwrap(), name(rng));
+        }
+    }
+    out
+}
+
+crate::solution::solution!(Vec<Line>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task7/example.txt").unwrap()).unwrap()),
+            95437,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task7/example.txt").unwrap()).unwrap()),
+            24933642,
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::error::ParseResult;
+use crate::gen::Rng;
+use crate::geometry::Dir;
+use crate::grid::{Grid, Pos};
+
+pub fn parse(inp: &str) -> ParseResult<Grid<u8>> {
+    Grid::parse(inp, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+pub fn part1(matrix: &Grid<u8>) -> usize {
+    let (h, w) = matrix.size();
+    let mut visible = Grid::<bool>::new(h, w);
+
+    let mut solve_line = |it: &mut dyn Iterator<Item = (usize, usize)>| {
+        let mut curr_max = None;
+        for pos in it {
+            if curr_max < Some(matrix[pos]) {
+                curr_max = Some(matrix[pos]);
+                visible[pos] = true;
+            }
+        }
+    };
+    for i in 0..h {
+        solve_line(&mut (0..w).map(|j| (i, j)));
+        solve_line(&mut (0..w).rev().map(|j| (i, j)));
+    }
+    for j in 0..w {
+        solve_line(&mut (0..h).map(|i| (i, j)));
+        solve_line(&mut (0..h).rev().map(|i| (i, j)));
+    }
+
+    visible.iter().filter(|&&v| v).count()
+}
+
+// Trees seen from `pos` when looking in `dir`, the blocking one included
+fn viewing_distance(matrix: &Grid<u8>, pos: Pos, dir: Dir) -> usize {
+    let mut distance = 0;
+    let mut at = pos;
+    while let Some(next) = matrix.offset(at, dir.offset()) {
+        distance += 1;
+        if matrix[next] >= matrix[pos] {
+            break;
+        }
+        at = next;
+    }
+    distance
+}
+
+fn score(matrix: &Grid<u8>, pos: Pos) -> usize {
+    Dir::ALL
+        .iter()
+        .map(|&dir| viewing_distance(matrix, pos, dir))
+        .product()
+}
+
+pub fn part2(matrix: &Grid<u8>) -> usize {
+    matrix
+        .positions()
+        .map(|pos| score(matrix, pos))
+        .max()
+        .expect("Non-empty")
+}
+
+// A square forest with `size` trees on a side
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    (0..side)
+        .map(|_| {
+            let row: String = (0..side).map(|_| rng.range(b'0'..=b'9') as char).collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+crate::solution::solution!(Grid<u8>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task8/example.txt").unwrap()).unwrap()),
+            21,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task8/example.txt").unwrap()).unwrap()),
+            8,
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::error::{field, ParseError, ParseResult};
+use crate::gen::Rng;
+use crate::geometry::{Dir, Point};
+use std::collections::HashSet;
+
+pub struct Motion {
+    direction: Dir,
+    steps: i32,
+}
+impl Motion {
+    fn parse(row: &str) -> ParseResult<Motion> {
+        let (direction, steps) = row
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(row, "Expected a direction and a step count"))?;
+        Ok(Motion {
+            direction: match direction {
+                "L" => Dir::Left,
+                "R" => Dir::Right,
+                "U" => Dir::Up,
+                "D" => Dir::Down,
+                _ => return Err(ParseError::new(direction, "Unknown direction")),
+            },
+            steps: field(steps, "step count")?,
+        })
+    }
+}
+
+// Where the knot goes after the previous one has moved
+fn relax(first: Point, second: Point) -> Point {
+    let diff = first - second;
+    if diff.x.abs() > 1 || diff.y.abs() > 1 {
+        second + diff.signum()
+    } else {
+        second
+    }
+}
+pub fn parse(inp: &str) -> ParseResult<Vec<Motion>> {
+    inp.lines().map(Motion::parse).collect()
+}
+
+fn solve(motions: &[Motion], knots: usize) -> HashSet<Point> {
+    assert!(knots > 1, "Not empty");
+    let mut visited = HashSet::new();
+    let mut rope = vec![Point::ZERO; knots];
+    visited.insert(*rope.last().unwrap());
+
+    for motion in motions {
+        for _ in 0..motion.steps {
+            rope[0] += motion.direction.offset();
+
+            for i in 1..knots {
+                rope[i] = relax(rope[i - 1], rope[i]);
+            }
+            visited.insert(*rope.last().unwrap());
+        }
+    }
+
+    visited
+}
+
+pub fn part1(motions: &[Motion]) -> usize {
+    solve(motions, 2).len()
+}
+
+pub fn part2(motions: &[Motion]) -> usize {
+    solve(motions, 10).len()
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.pick(&["L", "R", "U", "D"]),
+                rng.range(1..=20)
+            )
+        })
+        .collect()
+}
+
+crate::solution::solution!(Vec<Motion>);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use std::fs;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            part1(&parse(&fs::read_to_string("inputs/2022/task9/example.txt").unwrap()).unwrap()),
+            88,
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            part2(&parse(&fs::read_to_string("inputs/2022/task9/example.txt").unwrap()).unwrap()),
+            36,
+        );
+    }
+}